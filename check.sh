@@ -57,5 +57,7 @@ echo Using toolchain $TOOLCHAIN
 # tests (std+arc+debug+macro, only debug [skip doctest since they require macro])
 cargo $TOOLCHAIN test --locked --release --all-features -- --include-ignored
 cargo $TOOLCHAIN test --locked --release --no-default-features --features debug --lib --bins --tests -- --include-ignored
+# the serde tests with the default config of serde_json (the workspace enables arbitrary_precision)
+cargo $TOOLCHAIN test --locked --release -p scientific --features serde --test serde
 
 typos
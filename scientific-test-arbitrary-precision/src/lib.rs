@@ -0,0 +1,4 @@
+//! Tests of `scientific` which require `serde_json` with the feature `arbitrary_precision`.
+//!
+//! They are in a separate crate since the feature would otherwise change the behaviour of
+//! `serde_json` in all other tests.
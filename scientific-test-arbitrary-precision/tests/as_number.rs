@@ -0,0 +1,78 @@
+use scientific::Scientific;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[test]
+fn as_number() {
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Number {
+    #[serde(with = "scientific::serde::as_number")]
+    value: Scientific,
+  }
+
+  for (sci, json) in [
+    ("0", r#"{"value":0}"#),
+    ("-12340", r#"{"value":-12340}"#),
+    ("1.234", r#"{"value":1.234}"#),
+    ("123.4e99", r#"{"value":1.234e101}"#),
+    (
+      "1.0000000000000000000000000000001",
+      r#"{"value":1.0000000000000000000000000000001}"#,
+    ),
+  ] {
+    let number = Number {
+      value: Scientific::from_str(sci).unwrap(),
+    };
+    assert_eq!(
+      serde_json::to_string(&number).map_err(|_| ()),
+      Ok(json.to_string()),
+      "{sci} to json"
+    );
+    assert_eq!(
+      serde_json::from_str(json).map_err(|_| ()),
+      Ok(Number {
+        value: Scientific::from_str(sci).unwrap(),
+      }),
+      "{sci} from json"
+    );
+    assert_eq!(
+      postcard::from_bytes(&postcard::to_extend(&number, Vec::new()).unwrap()),
+      Ok(Number {
+        value: Scientific::from_str(sci).unwrap(),
+      }),
+      "{sci} postcard"
+    );
+  }
+
+  // strings are also accepted
+  assert_eq!(
+    serde_json::from_str(r#"{"value":"1.5"}"#).map_err(|_| ()),
+    Ok(Number {
+      value: Scientific::from_str("1.5").unwrap(),
+    })
+  );
+  // other values are not
+  assert!(serde_json::from_str::<Number>(r#"{"value":true}"#).is_err());
+  assert!(serde_json::from_str::<Number>(r#"{"value":{"a":1}}"#).is_err());
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct OptionNumber {
+    #[serde(with = "scientific::serde::as_number::option")]
+    value: Option<Scientific>,
+  }
+
+  for (value, json) in [
+    (
+      Some(Scientific::from_str("1e100").unwrap()),
+      r#"{"value":1e100}"#,
+    ),
+    (None, r#"{"value":null}"#),
+  ] {
+    let number = OptionNumber { value };
+    assert_eq!(
+      serde_json::to_string(&number).map_err(|_| ()),
+      Ok(json.to_string()),
+    );
+    assert_eq!(serde_json::from_str(json).map_err(|_| ()), Ok(number));
+  }
+}
@@ -0,0 +1,41 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::scientific::Scientific;
+use bigdecimal::BigDecimal;
+use core::convert::TryFrom;
+
+impl TryFrom<&BigDecimal> for Scientific {
+  type Error = ConversionError;
+
+  /// The conversion fails with [`ConversionError::ExponentTooLargeForThisPlatform`] if the
+  /// scale does not fit into an `isize`.
+  fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+    let (mantissa, scale) = value.as_bigint_and_exponent();
+    let scale =
+      isize::try_from(scale).map_err(|_| ConversionError::ExponentTooLargeForThisPlatform)?;
+    let mut result = Scientific::from(&mantissa);
+    result.inner.shr_assign(scale);
+    Ok(result)
+  }
+}
+
+impl TryFrom<BigDecimal> for Scientific {
+  type Error = ConversionError;
+
+  #[inline]
+  fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+    Scientific::try_from(&value)
+  }
+}
+
+impl From<&Scientific> for BigDecimal {
+  fn from(value: &Scientific) -> Self {
+    if value.is_zero() {
+      BigDecimal::default()
+    } else {
+      BigDecimal::new(
+        value.inner.mantissa_to_bigint(),
+        -(value.inner.exponent as i64),
+      )
+    }
+  }
+}
@@ -0,0 +1,93 @@
+use crate::conversion::radix::MAX_DIGITS;
+use crate::types::conversion_error::ConversionError;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use num_bigint::{BigInt, BigUint, Sign};
+
+impl Sci {
+  // the mantissa (without the exponent)
+  pub(crate) fn mantissa_to_bigint(&self) -> BigInt {
+    let limbs = self.mantissa_to_le_limbs();
+    let mut digits = Vec::with_capacity(2 * limbs.len());
+    for limb in limbs {
+      digits.push(limb as u32);
+      digits.push((limb >> 32) as u32);
+    }
+    let sign = if self.sign.is_negative() {
+      Sign::Minus
+    } else {
+      Sign::Plus
+    };
+    BigInt::new(sign, digits)
+  }
+
+  fn to_bigint(&self) -> Result<BigInt, ConversionError> {
+    if self.is_zero() {
+      return Ok(BigInt::default());
+    }
+    if self.exponent < 0 {
+      return Err(ConversionError::NumberIsNotAnInteger);
+    }
+    // like to_le_limbs, the trailing zeros are only expanded up to MAX_DIGITS
+    if self.exponent > MAX_DIGITS - self.len {
+      return Err(ConversionError::NumberTooLarge);
+    }
+    Ok(self.mantissa_to_bigint() * BigInt::from(10_u8).pow(self.exponent as u32))
+  }
+}
+
+impl From<&BigUint> for Scientific {
+  fn from(value: &BigUint) -> Self {
+    Scientific {
+      inner: Sci::from_le_limbs(false, &value.to_u64_digits(), 0),
+    }
+  }
+}
+
+impl From<BigUint> for Scientific {
+  #[inline]
+  fn from(value: BigUint) -> Self {
+    Scientific::from(&value)
+  }
+}
+
+impl TryFrom<&Scientific> for BigUint {
+  type Error = ConversionError;
+
+  fn try_from(value: &Scientific) -> Result<Self, Self::Error> {
+    if value.is_sign_negative() {
+      Err(ConversionError::NumberIsNegative)
+    } else {
+      Ok(value.inner.to_bigint()?.into_parts().1)
+    }
+  }
+}
+
+impl From<&BigInt> for Scientific {
+  fn from(value: &BigInt) -> Self {
+    Scientific {
+      inner: Sci::from_le_limbs(
+        value.sign() == Sign::Minus,
+        &value.magnitude().to_u64_digits(),
+        0,
+      ),
+    }
+  }
+}
+
+impl From<BigInt> for Scientific {
+  #[inline]
+  fn from(value: BigInt) -> Self {
+    Scientific::from(&value)
+  }
+}
+
+impl TryFrom<&Scientific> for BigInt {
+  type Error = ConversionError;
+
+  fn try_from(value: &Scientific) -> Result<Self, Self::Error> {
+    value.inner.to_bigint()
+  }
+}
@@ -0,0 +1,86 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::precision::Precision;
+use crate::types::rounding::Rounding;
+use crate::types::rounding_mode::RoundingMode;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+
+// The BSON Decimal128 is an IEEE 754-2008 decimal128 with binary integer decimal encoding
+// stored in little endian, as u128 (bit 127 is the most significant):
+// - bit 127: sign
+// - bits 126..113: biased exponent (14 bits), if bits 126..125 are not both set
+// - bits 112..0: coefficient (113 bits)
+// Values with bits 126..125 set are either infinity, NaN or have a coefficient > 10^34-1.
+const SIGN_BIT: u128 = 1 << 127;
+const COEFFICIENT_BITS: u32 = 113;
+const COMBINATION_MASK: u128 = 0b11 << 125;
+const INFINITY_MASK: u128 = 0b1_1110 << 122;
+const NAN_MASK: u128 = 0b1_1111 << 122;
+const EXPONENT_MASK: u128 = (1 << 14) - 1;
+const EXPONENT_BIAS: isize = 6176;
+const EXPONENT_MAX: isize = 6111;
+const DIGITS: isize = 34;
+const COEFFICIENT_MAX: u128 = 9_999_999_999_999_999_999_999_999_999_999_999;
+
+impl Sci {
+  pub(crate) fn to_bson_decimal128(&self, rounding: Rounding) -> Result<[u8; 16], ConversionError> {
+    // round to 34 digits, or less if the smallest exponent would be exceeded (subnormal)
+    let precision = if self.exponent0() - DIGITS < -EXPONENT_BIAS {
+      Precision::Decimals(EXPONENT_BIAS)
+    } else {
+      Precision::Digits(DIGITS)
+    };
+    let mut value = self.clone();
+    value.round_assign(precision, RoundingMode::Rounding(rounding));
+    if value.is_zero() {
+      // a zero (also a value rounded to zero) is always positive with the exponent 0
+      return Ok(((EXPONENT_BIAS as u128) << COEFFICIENT_BITS).to_le_bytes());
+    }
+
+    // the coefficient has at most 34 digits, clamp the exponent by adding zeroes to the coefficient
+    let clamp = (value.exponent - EXPONENT_MAX).max(0);
+    if value.len + clamp > DIGITS {
+      return Err(ConversionError::NumberTooLarge);
+    }
+    let mut coefficient = 0_u128;
+    for i in 0..value.len {
+      coefficient = coefficient * 10 + value.data[i] as u128;
+    }
+    coefficient *= 10_u128.pow(clamp as u32);
+
+    let mut result =
+      (((value.exponent - clamp + EXPONENT_BIAS) as u128) << COEFFICIENT_BITS) | coefficient;
+    if value.sign.is_negative() {
+      result |= SIGN_BIT;
+    }
+    Ok(result.to_le_bytes())
+  }
+
+  pub(crate) fn from_bson_decimal128(bytes: [u8; 16]) -> Result<Sci, ConversionError> {
+    let value = u128::from_le_bytes(bytes);
+    if value & INFINITY_MASK == INFINITY_MASK || value & NAN_MASK == NAN_MASK {
+      return Err(ConversionError::FloatIsNotFinite);
+    }
+
+    let (exponent, coefficient) = if value & COMBINATION_MASK == COMBINATION_MASK {
+      // the coefficient would be larger than 2^113 which is not canonical and is treated as zero
+      (0, 0)
+    } else {
+      (
+        ((value >> COEFFICIENT_BITS) & EXPONENT_MASK) as isize - EXPONENT_BIAS,
+        value & ((1 << COEFFICIENT_BITS) - 1),
+      )
+    };
+    if coefficient > COEFFICIENT_MAX {
+      // also not canonical
+      return Ok(Sci::ZERO);
+    }
+
+    let mut result = Scientific::from(coefficient).inner;
+    if value & SIGN_BIT != 0 {
+      result.neg_assign();
+    }
+    result.shl_assign(exponent);
+    Ok(result)
+  }
+}
@@ -4,11 +4,12 @@ use crate::types::ptr::Ptr;
 use crate::types::sci::Sci;
 use crate::types::sign::Sign;
 use alloc::vec::Vec;
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 
 impl Sci {
   #[allow(clippy::too_many_lines)]
-  pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Sci, ConversionError> {
+  // With STRICT only the canonical representation (which is created by to_bytes) is accepted.
+  pub(crate) fn from_bytes<const STRICT: bool>(bytes: &[u8]) -> Result<Sci, ConversionError> {
     if bytes.is_empty() {
       return Ok(Sci::ZERO);
     }
@@ -31,12 +32,18 @@ impl Sci {
       }
       exponent = (bytes[1] as i8) as isize;
       pos = 1 + 1;
+      if STRICT && (-64..=59).contains(&exponent) {
+        return Err(ConversionError::ParseError);
+      }
     } else if prefix == 0x3d {
       if bytes.len() < 1 + 2 {
         return Err(ConversionError::ParseError);
       }
       exponent = i16::from_be_bytes(bytes[1..=2].try_into().unwrap()) as isize;
       pos = 1 + 2;
+      if STRICT && i8::try_from(exponent).is_ok() {
+        return Err(ConversionError::ParseError);
+      }
     } else {
       #[cfg(target_pointer_width = "16")]
       return Err(ConversionError::ExponentTooLargeForThisPlatform);
@@ -48,6 +55,9 @@ impl Sci {
         }
         exponent = i32::from_be_bytes(bytes[1..=4].try_into().unwrap()) as isize;
         pos = 1 + 4;
+        if STRICT && i16::try_from(exponent).is_ok() {
+          return Err(ConversionError::ParseError);
+        }
       } else {
         #[cfg(target_pointer_width = "32")]
         return Err(ConversionError::ExponentTooLargeForThisPlatform);
@@ -59,6 +69,9 @@ impl Sci {
           }
           exponent = i64::from_be_bytes(bytes[1..=8].try_into().unwrap()) as isize;
           pos = 1 + 8;
+          if STRICT && i32::try_from(exponent).is_ok() {
+            return Err(ConversionError::ParseError);
+          }
         }
       }
 
@@ -96,7 +109,7 @@ impl Sci {
     while buf_len >= 4 {
       buf_len -= 4;
       let v = (buf >> buf_len) & 15;
-      if v > 10 {
+      if v >= 10 {
         return Err(ConversionError::ParseError);
       }
       owned.push(v as u8);
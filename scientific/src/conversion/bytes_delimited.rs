@@ -0,0 +1,84 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::sci::Sci;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::Read;
+
+// The length delimited format is the length of the compressed format as an unsigned LEB128
+// (7 bits per byte, least significant first, bit 7 is set if more bytes follow) followed by the
+// compressed format (see bytes_ser/bytes_de).
+
+impl Sci {
+  pub(crate) fn encode_into<E: Extend<u8>>(&self, target: &mut E) {
+    let bytes = self.to_bytes();
+    let mut len = bytes.len();
+    while len >= 0x80 {
+      target.extend(Some((len as u8) | 0x80));
+      len >>= 7;
+    }
+    target.extend(Some(len as u8));
+    target.extend(bytes);
+  }
+
+  pub(crate) fn decode_prefix(bytes: &[u8]) -> Result<(Sci, usize), ConversionError> {
+    let mut it = bytes.iter().copied();
+    let len = read_len(&mut || it.next().ok_or(ConversionError::ParseError))?;
+    let pos = bytes.len() - it.len();
+    let end = pos.checked_add(len).ok_or(ConversionError::ParseError)?;
+    if end > bytes.len() {
+      return Err(ConversionError::ParseError);
+    }
+    Ok((Sci::from_bytes::<false>(&bytes[pos..end])?, end))
+  }
+}
+
+// Read the length in the LEB128 format, only the shortest encoding is accepted.
+fn read_len<E, F>(next: &mut F) -> Result<usize, E>
+where
+  E: From<ConversionError>,
+  F: FnMut() -> Result<u8, E>,
+{
+  let mut len = 0_usize;
+  let mut shift = 0;
+  loop {
+    let b = next()?;
+    let value = usize::from(b & 0x7f);
+    if shift >= usize::BITS || (value << shift) >> shift != value {
+      return Err(ConversionError::ParseError.into());
+    }
+    len |= value << shift;
+    if b & 0x80 == 0 {
+      if b == 0 && shift > 0 {
+        // not the shortest encoding
+        return Err(ConversionError::ParseError.into());
+      }
+      return Ok(len);
+    }
+    shift += 7;
+  }
+}
+
+#[cfg(feature = "std")]
+impl Sci {
+  pub(crate) fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+    let mut buf = Vec::with_capacity((self.len as usize * 5) / 12 + 6);
+    self.encode_into(&mut buf);
+    writer.write_all(&buf)
+  }
+
+  pub(crate) fn read_from<R: Read>(reader: &mut R) -> std::io::Result<Sci> {
+    let len = read_len::<std::io::Error, _>(&mut || {
+      let mut b = [0];
+      reader.read_exact(&mut b)?;
+      Ok(b[0])
+    })?;
+    // the data is read in chunks and thus an invalid length does not cause a huge allocation
+    let mut buf = Vec::new();
+    reader.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() != len {
+      return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(Sci::from_bytes::<false>(&buf)?)
+  }
+}
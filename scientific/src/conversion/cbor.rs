@@ -0,0 +1,204 @@
+use crate::conversion::limbs::{mul_add_assign, trim};
+use crate::types::conversion_error::ConversionError;
+use crate::types::sci::Sci;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+// The CBOR decimal fraction (RFC 8949, section 3.4.4) is encoded as:
+// - tag 4
+// - array of length 2
+// - exponent (unsigned or negative integer)
+// - mantissa (unsigned or negative integer, or bignum with tag 2 or 3)
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_TAG: u8 = 6;
+// the additional information for arguments of 1, 2, 4 and 8 bytes
+const ARGUMENT_U8: u8 = 0x18;
+const ARGUMENT_U16: u8 = 0x19;
+const ARGUMENT_U32: u8 = 0x1a;
+const ARGUMENT_U64: u8 = 0x1b;
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+const TAG_DECIMAL_FRACTION: u64 = 4;
+
+impl Sci {
+  pub(crate) fn to_cbor_decimal(&self) -> Vec<u8> {
+    let mut result = Vec::new();
+    write_head(&mut result, MAJOR_TAG, TAG_DECIMAL_FRACTION);
+    write_head(&mut result, MAJOR_ARRAY, 2);
+
+    if self.is_zero() {
+      write_head(&mut result, MAJOR_UNSIGNED, 0);
+      write_head(&mut result, MAJOR_UNSIGNED, 0);
+      return result;
+    }
+
+    // a negative number `n` is stored as `-1 - n`
+    if self.exponent < 0 {
+      write_head(&mut result, MAJOR_NEGATIVE, (-1 - self.exponent) as u64);
+    } else {
+      write_head(&mut result, MAJOR_UNSIGNED, self.exponent as u64);
+    }
+
+    let mut limbs = self.mantissa_to_le_limbs();
+    let is_negative = self.sign.is_negative();
+    if is_negative {
+      sub_one(&mut limbs);
+    }
+    if trim(&mut limbs) <= 1 {
+      write_head(
+        &mut result,
+        if is_negative {
+          MAJOR_NEGATIVE
+        } else {
+          MAJOR_UNSIGNED
+        },
+        limbs.first().copied().unwrap_or(0),
+      );
+    } else {
+      write_head(
+        &mut result,
+        MAJOR_TAG,
+        if is_negative {
+          TAG_NEGATIVE_BIGNUM
+        } else {
+          TAG_POSITIVE_BIGNUM
+        },
+      );
+      let mut bytes = Vec::with_capacity(8 * limbs.len());
+      for limb in limbs.iter().rev() {
+        bytes.extend_from_slice(&limb.to_be_bytes());
+      }
+      let skip = bytes.iter().take_while(|b| **b == 0).count();
+      write_head(&mut result, MAJOR_BYTES, (bytes.len() - skip) as u64);
+      result.extend_from_slice(&bytes[skip..]);
+    }
+
+    result
+  }
+
+  pub(crate) fn from_cbor_decimal(bytes: &[u8]) -> Result<Sci, ConversionError> {
+    let mut reader = Reader { bytes, pos: 0 };
+
+    if reader.head()? != (MAJOR_TAG, TAG_DECIMAL_FRACTION) || reader.head()? != (MAJOR_ARRAY, 2) {
+      return Err(ConversionError::ParseError);
+    }
+
+    let exponent = match reader.head()? {
+      (MAJOR_UNSIGNED, e) => i128::from(e),
+      (MAJOR_NEGATIVE, e) => -1 - i128::from(e),
+      _ => return Err(ConversionError::ParseError),
+    };
+    let exponent =
+      isize::try_from(exponent).map_err(|_| ConversionError::ExponentTooLargeForThisPlatform)?;
+
+    let (is_negative, mut limbs) = match reader.head()? {
+      (MAJOR_UNSIGNED, m) => (false, vec![m]),
+      (MAJOR_NEGATIVE, m) => (true, vec![m]),
+      (MAJOR_TAG, tag @ (TAG_POSITIVE_BIGNUM | TAG_NEGATIVE_BIGNUM)) => {
+        let bytes = match reader.head()? {
+          (MAJOR_BYTES, len) => reader.take(len)?,
+          _ => return Err(ConversionError::ParseError),
+        };
+        let mut limbs = Vec::with_capacity(bytes.len() / 8 + 1);
+        for chunk in bytes.rchunks(8) {
+          let mut limb = [0; 8];
+          limb[8 - chunk.len()..].copy_from_slice(chunk);
+          limbs.push(u64::from_be_bytes(limb));
+        }
+        (tag == TAG_NEGATIVE_BIGNUM, limbs)
+      }
+      _ => return Err(ConversionError::ParseError),
+    };
+
+    if reader.pos != bytes.len() {
+      return Err(ConversionError::ParseError);
+    }
+
+    if is_negative {
+      mul_add_assign(&mut limbs, 1, 1);
+    }
+    let result = Sci::from_le_limbs(is_negative, &limbs, exponent);
+    if exponent.checked_add(result.len).is_none() {
+      return Err(ConversionError::ExponentTooLargeForThisPlatform);
+    }
+    Ok(result)
+  }
+}
+
+// subtract one from a non-zero number
+fn sub_one(value: &mut [u64]) {
+  for limb in value.iter_mut() {
+    let (v, borrow) = limb.overflowing_sub(1);
+    *limb = v;
+    if !borrow {
+      break;
+    }
+  }
+}
+
+fn write_head(result: &mut Vec<u8>, major: u8, value: u64) {
+  let major = major << 5;
+  if value < u64::from(ARGUMENT_U8) {
+    result.push(major | value as u8);
+  } else if let Ok(value) = u8::try_from(value) {
+    result.push(major | ARGUMENT_U8);
+    result.push(value);
+  } else if let Ok(value) = u16::try_from(value) {
+    result.push(major | ARGUMENT_U16);
+    result.extend_from_slice(&value.to_be_bytes());
+  } else if let Ok(value) = u32::try_from(value) {
+    result.push(major | ARGUMENT_U32);
+    result.extend_from_slice(&value.to_be_bytes());
+  } else {
+    result.push(major | ARGUMENT_U64);
+    result.extend_from_slice(&value.to_be_bytes());
+  }
+}
+
+struct Reader<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> Reader<'a> {
+  fn take(&mut self, len: u64) -> Result<&'a [u8], ConversionError> {
+    let len = usize::try_from(len).map_err(|_| ConversionError::ParseError)?;
+    let end = self
+      .pos
+      .checked_add(len)
+      .filter(|end| *end <= self.bytes.len())
+      .ok_or(ConversionError::ParseError)?;
+    let result = &self.bytes[self.pos..end];
+    self.pos = end;
+    Ok(result)
+  }
+
+  // read the major type and argument of an item, indefinite lengths are not supported
+  fn head(&mut self) -> Result<(u8, u64), ConversionError> {
+    let initial = self.take(1)?[0];
+    let value = match initial & 0x1f {
+      v if v < ARGUMENT_U8 => u64::from(v),
+      ARGUMENT_U8 => u64::from(self.take(1)?[0]),
+      ARGUMENT_U16 => {
+        let mut v = [0; 2];
+        v.copy_from_slice(self.take(2)?);
+        u64::from(u16::from_be_bytes(v))
+      }
+      ARGUMENT_U32 => {
+        let mut v = [0; 4];
+        v.copy_from_slice(self.take(4)?);
+        u64::from(u32::from_be_bytes(v))
+      }
+      ARGUMENT_U64 => {
+        let mut v = [0; 8];
+        v.copy_from_slice(self.take(8)?);
+        u64::from_be_bytes(v)
+      }
+      _ => return Err(ConversionError::ParseError),
+    };
+    Ok((initial >> 5, value))
+  }
+}
@@ -0,0 +1,52 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::scientific::Scientific;
+use core::convert::TryFrom;
+use rust_decimal::Decimal;
+
+// The maximum scale of a `Decimal`.
+const MAX_SCALE: isize = 28;
+// The maximum number of digits of the mantissa (which has 96 bits).
+const MAX_DIGITS: isize = 29;
+
+impl From<&Decimal> for Scientific {
+  fn from(value: &Decimal) -> Self {
+    let mut result = Scientific::from(value.mantissa());
+    result.inner.shr_assign(value.scale() as isize);
+    result
+  }
+}
+
+impl From<Decimal> for Scientific {
+  #[inline]
+  fn from(value: Decimal) -> Self {
+    Scientific::from(&value)
+  }
+}
+
+impl TryFrom<&Scientific> for Decimal {
+  type Error = ConversionError;
+
+  /// The conversion is exact, it fails with [`ConversionError::NumberTooLarge`] if the value
+  /// has more than 28 decimals or the mantissa does not fit into 96 bits.
+  fn try_from(value: &Scientific) -> Result<Self, Self::Error> {
+    let value = &value.inner;
+    if value.is_zero() {
+      return Ok(Decimal::ZERO);
+    }
+    if value.exponent < -MAX_SCALE || value.len > MAX_DIGITS || value.exponent0() > MAX_DIGITS {
+      return Err(ConversionError::NumberTooLarge);
+    }
+    let mut mantissa = 0_i128;
+    for i in 0..value.len {
+      mantissa = mantissa * 10 + i128::from(value.data[i]);
+    }
+    if value.exponent > 0 {
+      mantissa *= 10_i128.pow(value.exponent as u32);
+    }
+    if value.sign.is_negative() {
+      mantissa = -mantissa;
+    }
+    Decimal::try_from_i128_with_scale(mantissa, (-value.exponent).max(0) as u32)
+      .map_err(|_| ConversionError::NumberTooLarge)
+  }
+}
@@ -1,10 +1,47 @@
+use crate::conversion::integer::operator;
+use crate::types::conversion_error::ConversionError;
 use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
 use crate::types::sign::Sign;
 use alloc::string::String;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
 use core::fmt::Write;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign};
 use core::str::FromStr;
 
+const MANTISSA_2: [u8; 1] = [2];
+const MANTISSA_5: [u8; 1] = [5];
+const TWO: Sci = Sci::nz_unchecked_static_new(Sign::POSITIVE, &MANTISSA_2, 0);
+const FIVE: Sci = Sci::nz_unchecked_static_new(Sign::POSITIVE, &MANTISSA_5, 0);
+
 impl Sci {
+  // The value must be finite.
+  pub(crate) fn from_f64_exact(value: f64) -> Sci {
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as isize;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+      // subnormal
+      (fraction, -1074)
+    } else {
+      (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    let mut result = Scientific::from(mantissa).inner;
+    if result.is_zero() {
+      return Sci::ZERO;
+    }
+    if exponent >= 0 {
+      result = result.mul(&TWO.powi(exponent as usize));
+    } else {
+      // m * 2^-e = m * 5^e * 10^-e
+      result = result.mul(&FIVE.powi(-exponent as usize));
+      result.shr_assign(-exponent);
+    }
+    result.sign = Sign::new(bits >> 63 != 0);
+    result
+  }
+
   pub(crate) fn to_f64(&self) -> f64 {
     if self.is_zero() {
       0f64
@@ -57,3 +94,75 @@ impl Sci {
     }
   }
 }
+
+// Operators and comparisons with floats. The operators convert the float with `TryFrom` and panic
+// if it is not finite, the comparisons use the exact binary value of the float (like
+// `from_f64_exact`), NaN is unordered and the infinities are larger/smaller than all values.
+macro_rules! float_operators {
+  ($ty:ident) => {
+    float_operators!(
+      /// # Panics
+      ///
+      /// Will panic if the float is not finite (NaN or infinite).
+      $ty
+    );
+  };
+  ($(#[$attr:meta])* $ty:ident) => {
+    operator!($(#[$attr])* $ty, from_finite, Add, add, AddAssign, add_assign);
+    operator!($(#[$attr])* $ty, from_finite, Sub, sub, SubAssign, sub_assign);
+    operator!($(#[$attr])* $ty, from_finite, Mul, mul, MulAssign, mul_assign);
+    operator!($(#[$attr])* $ty, from_finite, Div, div);
+    operator!($(#[$attr])* $ty, from_finite, Rem, rem);
+
+    impl PartialEq<$ty> for Scientific {
+      #[inline]
+      fn eq(&self, other: &$ty) -> bool {
+        from_exact(*other).map_or(false, |other| *self == other)
+      }
+    }
+
+    impl PartialEq<Scientific> for $ty {
+      #[inline]
+      fn eq(&self, other: &Scientific) -> bool {
+        other == self
+      }
+    }
+
+    impl PartialOrd<$ty> for Scientific {
+      #[inline]
+      fn partial_cmp(&self, other: &$ty) -> Option<Ordering> {
+        match from_exact(*other) {
+          Some(other) => Some(self.cmp(&other)),
+          None if other.is_nan() => None,
+          None if *other > 0.0 => Some(Ordering::Less),
+          None => Some(Ordering::Greater),
+        }
+      }
+    }
+
+    impl PartialOrd<Scientific> for $ty {
+      #[inline]
+      fn partial_cmp(&self, other: &Scientific) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+      }
+    }
+  };
+}
+
+fn from_finite<F>(value: F) -> Scientific
+where
+  Scientific: TryFrom<F, Error = ConversionError>,
+{
+  Scientific::try_from(value).expect("the float is not finite")
+}
+
+// the exact binary value (every f32 is exactly representable as f64), None if it is not finite
+fn from_exact<F: Into<f64>>(value: F) -> Option<Scientific> {
+  let value = value.into();
+  value.is_finite().then(|| Scientific {
+    inner: Sci::from_f64_exact(value),
+  })
+}
+
+float_operators!(f32);
+float_operators!(f64);
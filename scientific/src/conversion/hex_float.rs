@@ -0,0 +1,125 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::precision::Precision;
+use crate::types::rounding::Rounding;
+use crate::types::rounding_mode::RoundingMode;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+use alloc::format;
+use alloc::string::String;
+
+const MANTISSA_2: [u8; 1] = [2];
+const TWO: Sci = Sci::nz_unchecked_static_new(Sign::POSITIVE, &MANTISSA_2, 0);
+
+// log2(10) * 10^18, used to estimate the binary exponent from the decimal exponent
+const LOG2_10: i128 = 3_321_928_094_887_362_347;
+const LOG2_10_SCALE: i128 = 1_000_000_000_000_000_000;
+
+// The limit of the binary exponent (more than the range of IEEE 754 binary128), larger exponents
+// would need an unbounded amount of time and memory.
+const MAX_EXPONENT: isize = 20000;
+
+impl Sci {
+  pub(crate) fn from_hex_float(value: &str) -> Result<Sci, ConversionError> {
+    let (is_negative, value) = match value.as_bytes().first() {
+      Some(b'-') => (true, &value[1..]),
+      Some(b'+') => (false, &value[1..]),
+      _ => (false, value),
+    };
+    if ["inf", "infinity", "nan"]
+      .iter()
+      .any(|special| value.eq_ignore_ascii_case(special))
+    {
+      return Err(ConversionError::FloatIsNotFinite);
+    }
+    let value = value
+      .strip_prefix("0x")
+      .or_else(|| value.strip_prefix("0X"))
+      .ok_or(ConversionError::ParseError)?;
+
+    // the binary exponent is optional
+    let (mantissa, exponent) = match value.find(['p', 'P']) {
+      Some(pos) => (
+        &value[..pos],
+        value[pos + 1..]
+          .parse::<isize>()
+          .ok()
+          .filter(|exponent| exponent.abs() <= MAX_EXPONENT)
+          .ok_or(ConversionError::ParseError)?,
+      ),
+      None => (value, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = [integer, fraction].concat();
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+      return Err(ConversionError::ParseError);
+    }
+    let exponent = isize::try_from(fraction.len())
+      .ok()
+      .and_then(|len| len.checked_mul(4))
+      .and_then(|len| exponent.checked_sub(len))
+      .ok_or(ConversionError::ParseError)?;
+
+    let mut result = Sci::from_str_radix_integer(&digits, 16)?.mul(&pow2(exponent));
+    if is_negative {
+      result.neg_assign();
+    }
+    Ok(result)
+  }
+
+  pub(crate) fn to_hex_float(&self, precision: usize) -> String {
+    let sign = if self.sign.is_negative() && !self.is_zero() {
+      "-"
+    } else {
+      ""
+    };
+    if self.is_zero() {
+      let zeroes = "0".repeat(precision);
+      let point = if precision > 0 { "." } else { "" };
+      return format!("0x0{point}{zeroes}p+0");
+    }
+    let mut value = self.clone();
+    value.sign = Sign::POSITIVE;
+
+    // find the exponent with 1 <= value / 2^exponent < 2, starting with an estimate which is
+    // at most a few steps too small
+    let mut exponent = (self.exponent1() as i128 * LOG2_10).div_euclid(LOG2_10_SCALE) as isize;
+    let mut normalized = value.mul(&pow2(-exponent));
+    while normalized.compare::<false>(&TWO).is_ge() {
+      normalized = normalized.mul(&Sci::POINT5);
+      exponent += 1;
+    }
+    while normalized.compare::<false>(&Sci::ONE).is_lt() {
+      normalized = normalized.mul(&TWO);
+      exponent -= 1;
+    }
+
+    // the mantissa as integer with `precision` hexadecimal digits after the point
+    let mut mantissa = normalized.mul(&pow2(4 * precision as isize));
+    mantissa.round_assign(
+      Precision::INTEGER,
+      RoundingMode::Rounding(Rounding::RoundHalfToEven),
+    );
+    let mut digits = mantissa
+      .to_str_radix(16)
+      .expect("the mantissa is rounded to an integer")
+      .into_bytes();
+    if digits[0] == b'2' {
+      // rounded up to 2 (followed by zeroes)
+      digits[0] = b'1';
+      exponent += 1;
+    }
+    let fraction = core::str::from_utf8(&digits[1..]).unwrap_or_default();
+    let point = if precision > 0 { "." } else { "" };
+    let exponent_sign = if exponent >= 0 { "+" } else { "" };
+    format!("{sign}0x1{point}{fraction}p{exponent_sign}{exponent}")
+  }
+}
+
+// 2^exponent, which is exact also for negative exponents since 2^-n = 0.5^n
+fn pow2(exponent: isize) -> Sci {
+  if exponent >= 0 {
+    TWO.powi(exponent as usize)
+  } else {
+    Sci::POINT5.powi(exponent.unsigned_abs())
+  }
+}
@@ -1,5 +1,103 @@
 #![allow(clippy::wildcard_imports)]
 
+// Operators and comparisons with primitive integers, which are converted into a value first.
+macro_rules! operators {
+  ($ty:ident) => {
+    operator!($ty, Scientific::from, Add, add, AddAssign, add_assign);
+    operator!($ty, Scientific::from, Sub, sub, SubAssign, sub_assign);
+    operator!($ty, Scientific::from, Mul, mul, MulAssign, mul_assign);
+    operator!($ty, Scientific::from, Div, div);
+    operator!($ty, Scientific::from, Rem, rem);
+
+    impl PartialEq<$ty> for Scientific {
+      #[inline]
+      fn eq(&self, other: &$ty) -> bool {
+        $ty::try_from(self).map_or(false, |value| value == *other)
+      }
+    }
+
+    impl PartialEq<Scientific> for $ty {
+      #[inline]
+      fn eq(&self, other: &Scientific) -> bool {
+        $ty::try_from(other).map_or(false, |value| value == *self)
+      }
+    }
+
+    impl PartialOrd<$ty> for Scientific {
+      #[inline]
+      fn partial_cmp(&self, other: &$ty) -> Option<Ordering> {
+        Some(self.cmp(&Scientific::from(*other)))
+      }
+    }
+
+    impl PartialOrd<Scientific> for $ty {
+      #[inline]
+      fn partial_cmp(&self, other: &Scientific) -> Option<Ordering> {
+        Some(Scientific::from(*self).cmp(other))
+      }
+    }
+  };
+}
+
+// An operator with a primitive type, which is converted with `$from` (also used for floats),
+// the attributes (e.g. docs) are added to each fn.
+macro_rules! operator {
+  ($(#[$attr:meta])* $ty:ident, $from:path, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+    operator!($(#[$attr])* $ty, $from, $trait, $fn);
+
+    impl $assign_trait<$ty> for Scientific {
+      $(#[$attr])*
+      #[inline]
+      fn $assign_fn(&mut self, rhs: $ty) {
+        <Scientific as $assign_trait<&Scientific>>::$assign_fn(self, &$from(rhs));
+      }
+    }
+  };
+  ($(#[$attr:meta])* $ty:ident, $from:path, $trait:ident, $fn:ident) => {
+    impl $trait<$ty> for Scientific {
+      type Output = Scientific;
+
+      $(#[$attr])*
+      #[inline]
+      fn $fn(self, rhs: $ty) -> Self::Output {
+        <Scientific as $trait>::$fn(self, $from(rhs))
+      }
+    }
+
+    impl $trait<$ty> for &Scientific {
+      type Output = Scientific;
+
+      $(#[$attr])*
+      #[inline]
+      fn $fn(self, rhs: $ty) -> Self::Output {
+        <&Scientific as $trait>::$fn(self, &$from(rhs))
+      }
+    }
+
+    impl $trait<Scientific> for $ty {
+      type Output = Scientific;
+
+      $(#[$attr])*
+      #[inline]
+      fn $fn(self, rhs: Scientific) -> Self::Output {
+        <Scientific as $trait>::$fn($from(self), rhs)
+      }
+    }
+
+    impl $trait<&Scientific> for $ty {
+      type Output = Scientific;
+
+      $(#[$attr])*
+      #[inline]
+      fn $fn(self, rhs: &Scientific) -> Self::Output {
+        <&Scientific as $trait>::$fn(&$from(self), rhs)
+      }
+    }
+  };
+}
+
+pub(crate) use operator;
+
 macro_rules! conversion_signed {
   ($ty:ident, $len:expr, $const:ident) => {
     impl TryFrom<&Scientific> for $ty {
@@ -67,6 +165,8 @@ macro_rules! conversion_signed {
         }
       }
     }
+
+    operators!($ty);
   };
 }
 
@@ -118,6 +218,8 @@ macro_rules! conversion_unsigned {
         }
       }
     }
+
+    operators!($ty);
   };
 }
 
@@ -129,6 +231,7 @@ mod c_use {
   pub(super) use crate::types::sign::Sign;
   pub(super) use core::cmp::Ordering;
   pub(super) use core::convert::TryFrom;
+  pub(super) use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign};
 }
 
 mod c_i8 {
@@ -220,7 +323,11 @@ mod c_u128 {
 mod c_isize {
   use crate::types::conversion_error::ConversionError;
   use crate::types::scientific::Scientific;
+  use core::cmp::Ordering;
   use core::convert::{TryFrom, TryInto};
+  use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign};
+
+  operators!(isize);
 
   impl TryFrom<&Scientific> for isize {
     type Error = ConversionError;
@@ -262,7 +369,11 @@ mod c_isize {
 mod c_usize {
   use crate::types::conversion_error::ConversionError;
   use crate::types::scientific::Scientific;
+  use core::cmp::Ordering;
   use core::convert::{TryFrom, TryInto};
+  use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign};
+
+  operators!(usize);
 
   impl TryFrom<&Scientific> for usize {
     type Error = ConversionError;
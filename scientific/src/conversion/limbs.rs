@@ -0,0 +1,314 @@
+use crate::types::builder::Builder;
+use crate::types::owner::Owner;
+use crate::types::ptr::Ptr;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// The largest power of ten which fits into an u64.
+pub(crate) const U64_TEN_POW: u64 = 10_000_000_000_000_000_000;
+pub(crate) const U64_TEN_POW_DIGITS: usize = 19;
+
+// Below these lengths (in limbs) the quadratic algorithms are faster.
+const KARATSUBA_THRESHOLD: usize = 32;
+const CONVERT_THRESHOLD: usize = 32;
+
+impl Sci {
+  // the mantissa as little endian u64 limbs (empty for zero)
+  pub(crate) fn mantissa_to_le_limbs(&self) -> Vec<u64> {
+    if self.is_zero() {
+      Vec::new()
+    } else {
+      digits_to_le_limbs(self.data.as_slice(self.len))
+    }
+  }
+
+  // the value of the integer in little endian u64 limbs times 10^exponent
+  pub(crate) fn from_le_limbs(is_negative: bool, limbs: &[u64], exponent: isize) -> Sci {
+    let mantissa = le_limbs_to_digits(limbs);
+    let len = mantissa.len() as isize;
+    Builder::from_data(
+      Sign::new(is_negative),
+      Ptr::new(mantissa.as_slice()),
+      len,
+      exponent,
+      Owner::new(mantissa),
+    )
+  }
+}
+
+// The base of little endian limbs: 2^64 or a power of a radix which fits into an u64.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Base(u64);
+
+impl Base {
+  pub(crate) const BINARY: Base = Base(0);
+  pub(crate) const DECIMAL: Base = Base(U64_TEN_POW);
+
+  // The largest power of the radix which fits into the limbs and its exponent (the digits per limb).
+  pub(crate) fn radix(radix: u64) -> (Base, usize) {
+    let mut base = radix;
+    let mut digits = 1;
+    while let Some(next) = base.checked_mul(radix) {
+      base = next;
+      digits += 1;
+    }
+    if u128::from(base) * u128::from(radix) == 1 << 64 {
+      // radix 2, 4 and 16 fit exactly into 2^64, no conversion is needed from/to binary
+      (Base::BINARY, digits + 1)
+    } else {
+      (Base(base), digits)
+    }
+  }
+
+  #[inline]
+  fn value(self) -> u128 {
+    if self.0 == 0 {
+      1 << 64
+    } else {
+      u128::from(self.0)
+    }
+  }
+
+  // split a value < base^2 into the lower limb and the carry
+  #[inline]
+  fn split(self, value: u128) -> (u64, u128) {
+    if self.0 == 0 {
+      (value as u64, value >> 64)
+    } else {
+      let base = u128::from(self.0);
+      ((value % base) as u64, value / base)
+    }
+  }
+
+  fn limbs_of(self, mut value: u128) -> Vec<u64> {
+    let mut result = Vec::new();
+    while value > 0 {
+      let (limb, carry) = self.split(value);
+      result.push(limb);
+      value = carry;
+    }
+    result
+  }
+}
+
+// Convert decimal digits (most significant first) into little endian u64 limbs.
+pub(crate) fn digits_to_le_limbs(digits: &[u8]) -> Vec<u64> {
+  convert(
+    &digits_to_limbs(digits, 10, U64_TEN_POW_DIGITS),
+    Base::DECIMAL,
+    Base::BINARY,
+  )
+}
+
+// Convert little endian u64 limbs into decimal digits (most significant first, without leading zeroes).
+pub(crate) fn le_limbs_to_digits(limbs: &[u64]) -> Vec<u8> {
+  limbs_to_digits(
+    &convert(limbs, Base::BINARY, Base::DECIMAL),
+    10,
+    U64_TEN_POW_DIGITS,
+  )
+}
+
+// Group digits (most significant first) into little endian limbs of `digits` digits each.
+pub(crate) fn digits_to_limbs(digits: &[u8], radix: u64, digits_per_limb: usize) -> Vec<u64> {
+  digits
+    .rchunks(digits_per_limb)
+    .map(|chunk| {
+      chunk
+        .iter()
+        .fold(0, |limb, digit| limb * radix + u64::from(*digit))
+    })
+    .collect()
+}
+
+// Split little endian limbs into digits (most significant first, without leading zeroes).
+pub(crate) fn limbs_to_digits(limbs: &[u64], radix: u64, digits_per_limb: usize) -> Vec<u8> {
+  let mut result = Vec::with_capacity(limbs.len() * digits_per_limb);
+  for limb in limbs {
+    let mut limb = *limb;
+    for _ in 0..digits_per_limb {
+      result.push((limb % radix) as u8);
+      limb /= radix;
+    }
+  }
+  while result.last() == Some(&0) {
+    result.pop();
+  }
+  result.reverse();
+  result
+}
+
+// Convert little endian limbs from one base into another (without leading zero limbs).
+//
+// This is a divide and conquer algorithm: the value is split into `high * from^h + low` and both
+// halves are converted recursively. The powers `from^h` are calculated in the target base by
+// repeated squaring, together with the Karatsuba multiplication the conversion is subquadratic.
+pub(crate) fn convert(value: &[u64], from: Base, to: Base) -> Vec<u64> {
+  let value = trimmed(value);
+  if from == to {
+    return value.to_vec();
+  }
+  // powers[k] = from^(2^k) in the base `to`
+  let mut powers = vec![to.limbs_of(from.value())];
+  while 1 << powers.len() < value.len() {
+    let last = &powers[powers.len() - 1];
+    let next = mul(last, last, to);
+    powers.push(next);
+  }
+  convert_rec(value, to, &powers)
+}
+
+fn convert_rec(value: &[u64], to: Base, powers: &[Vec<u64>]) -> Vec<u64> {
+  let value = trimmed(value);
+  if value.len() <= CONVERT_THRESHOLD {
+    let mut result = Vec::new();
+    for limb in value.iter().rev() {
+      result = mul(&result, &powers[0], to);
+      add_assign(&mut result, &to.limbs_of(u128::from(*limb)), to);
+    }
+    return result;
+  }
+  // the largest power of two smaller than the length
+  let k = (usize::BITS - 1 - (value.len() - 1).leading_zeros()) as usize;
+  let (low, high) = value.split_at(1 << k);
+  let mut result = mul(&convert_rec(high, to, powers), &powers[k], to);
+  add_assign(&mut result, &convert_rec(low, to, powers), to);
+  result
+}
+
+// Multiply two numbers in little endian limbs (without leading zero limbs).
+pub(crate) fn mul(a: &[u64], b: &[u64], base: Base) -> Vec<u64> {
+  if a.is_empty() || b.is_empty() {
+    return Vec::new();
+  }
+  let mut result = vec![0; a.len() + b.len()];
+  mul_add_into(&mut result, a, b, base);
+  trim(&mut result);
+  result
+}
+
+// result += a * b, the result must be large enough to hold the sum
+fn mul_add_into(result: &mut [u64], a: &[u64], b: &[u64], base: Base) {
+  let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+  if b.len() < KARATSUBA_THRESHOLD {
+    for (i, x) in b.iter().enumerate() {
+      if *x == 0 {
+        continue;
+      }
+      let mut carry = 0_u128;
+      for (j, y) in a.iter().enumerate() {
+        let v = u128::from(*x) * u128::from(*y) + u128::from(result[i + j]) + carry;
+        let (limb, c) = base.split(v);
+        result[i + j] = limb;
+        carry = c;
+      }
+      add_into(&mut result[i + a.len()..], &base.limbs_of(carry), base);
+    }
+  } else if 2 * b.len() <= a.len() {
+    // unbalanced, multiply chunks of a with b
+    for (i, chunk) in a.chunks(b.len()).enumerate() {
+      mul_add_into(&mut result[i * b.len()..], chunk, b, base);
+    }
+  } else {
+    // Karatsuba: (a1 x + a0) (b1 x + b0) = a1 b1 x^2 + ((a0 + a1) (b0 + b1) - a0 b0 - a1 b1) x + a0 b0
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = mul(trimmed(a0), trimmed(b0), base);
+    let z2 = mul(a1, trimmed(b1), base);
+    let mut a01 = trimmed(a0).to_vec();
+    add_assign(&mut a01, a1, base);
+    let mut b01 = trimmed(b0).to_vec();
+    add_assign(&mut b01, b1, base);
+    let mut z1 = mul(&a01, &b01, base);
+    sub_assign(&mut z1, &z0, base);
+    sub_assign(&mut z1, &z2, base);
+    add_into(result, &z0, base);
+    add_into(&mut result[m..], trimmed(&z1), base);
+    add_into(&mut result[2 * m..], &z2, base);
+  }
+}
+
+// value += a
+fn add_assign(value: &mut Vec<u64>, a: &[u64], base: Base) {
+  if value.len() <= a.len() {
+    value.resize(a.len() + 1, 0);
+  } else {
+    value.push(0);
+  }
+  add_into(value, a, base);
+  trim(value);
+}
+
+// value += a, the value must be large enough to hold the sum
+fn add_into(value: &mut [u64], a: &[u64], base: Base) {
+  let base = base.value();
+  let mut carry = 0;
+  for (i, limb) in value.iter_mut().enumerate() {
+    if i >= a.len() && carry == 0 {
+      return;
+    }
+    let v = u128::from(*limb) + u128::from(a.get(i).copied().unwrap_or(0)) + carry;
+    if v >= base {
+      *limb = (v - base) as u64;
+      carry = 1;
+    } else {
+      *limb = v as u64;
+      carry = 0;
+    }
+  }
+  debug_assert!(carry == 0);
+}
+
+// value -= a, the value must not be smaller than a
+fn sub_assign(value: &mut Vec<u64>, a: &[u64], base: Base) {
+  let base = base.value();
+  let mut borrow = 0;
+  for (i, limb) in value.iter_mut().enumerate() {
+    if i >= a.len() && borrow == 0 {
+      break;
+    }
+    let s = u128::from(a.get(i).copied().unwrap_or(0)) + borrow;
+    let v = u128::from(*limb);
+    if v >= s {
+      *limb = (v - s) as u64;
+      borrow = 0;
+    } else {
+      *limb = (v + base - s) as u64;
+      borrow = 1;
+    }
+  }
+  debug_assert!(borrow == 0);
+  trim(value);
+}
+
+// value = value * mul + add
+pub(crate) fn mul_add_assign(value: &mut Vec<u64>, mul: u64, add: u64) {
+  let mut carry = u128::from(add);
+  for limb in value.iter_mut() {
+    let v = u128::from(*limb) * u128::from(mul) + carry;
+    *limb = v as u64;
+    carry = v >> 64;
+  }
+  if carry > 0 {
+    value.push(carry as u64);
+  }
+}
+
+// remove zero limbs at the end, returns the new length
+pub(crate) fn trim(value: &mut Vec<u64>) -> usize {
+  while value.last() == Some(&0) {
+    value.pop();
+  }
+  value.len()
+}
+
+fn trimmed(value: &[u64]) -> &[u64] {
+  let len = value
+    .iter()
+    .rposition(|limb| *limb != 0)
+    .map_or(0, |i| i + 1);
+  &value[..len]
+}
@@ -1,6 +1,22 @@
+#[cfg(feature = "bigdecimal")]
+pub(crate) mod big_decimal;
+#[cfg(feature = "num-bigint")]
+pub(crate) mod bigint;
+pub(crate) mod bson_decimal128;
 pub(crate) mod bytes_de;
+pub(crate) mod bytes_delimited;
 pub(crate) mod bytes_ser;
+pub(crate) mod cbor;
+#[cfg(feature = "rust_decimal")]
+pub(crate) mod decimal;
 pub(crate) mod float;
+pub(crate) mod hex_float;
 pub(crate) mod integer;
+pub(crate) mod limbs;
+#[cfg(feature = "postgres")]
+pub(crate) mod postgres;
+pub(crate) mod radix;
 pub(crate) mod raw_parts;
+pub(crate) mod scaled_integer;
+pub(crate) mod sortable_bytes;
 pub(crate) mod string;
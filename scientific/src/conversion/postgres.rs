@@ -0,0 +1,140 @@
+use crate::types::builder::Builder;
+use crate::types::conversion_error::ConversionError;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+// The binary format of the Postgres NUMERIC type (all values are big endian):
+// - ndigits (i16): number of base 10000 digits
+// - weight (i16): weight of the first digit (the value is `digit[i] * 10000^(weight-i)`)
+// - sign (u16): see below
+// - dscale (u16): number of decimal digits after the decimal point (only used for display)
+// - ndigits times a digit (i16, 0..=9999)
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xc000;
+const NUMERIC_PINF: u16 = 0xd000;
+const NUMERIC_NINF: u16 = 0xf000;
+// The maximum dscale supported by Postgres.
+const NUMERIC_DSCALE_MAX: isize = 0x3fff;
+
+impl Sci {
+  pub(crate) fn to_postgres_numeric(&self) -> Result<Vec<u8>, ConversionError> {
+    if self.is_zero() {
+      return Ok(vec![0; 8]);
+    }
+
+    // the power (of ten) of the first and last digit
+    let first_power = self.exponent1();
+    let last_power = self.exponent;
+    let weight = first_power.div_euclid(4);
+    let last_group = last_power.div_euclid(4);
+    let ndigits = weight - last_group + 1;
+    let dscale = (-last_power).max(0);
+    if dscale > NUMERIC_DSCALE_MAX {
+      return Err(ConversionError::NumberTooLarge);
+    }
+    let ndigits = i16::try_from(ndigits).map_err(|_| ConversionError::NumberTooLarge)?;
+    let weight = i16::try_from(weight).map_err(|_| ConversionError::NumberTooLarge)?;
+
+    let mut result = Vec::with_capacity(8 + 2 * ndigits as usize);
+    result.extend_from_slice(&ndigits.to_be_bytes());
+    result.extend_from_slice(&weight.to_be_bytes());
+    result.extend_from_slice(
+      &(if self.sign.is_negative() {
+        NUMERIC_NEG
+      } else {
+        NUMERIC_POS
+      })
+      .to_be_bytes(),
+    );
+    result.extend_from_slice(&(dscale as u16).to_be_bytes());
+
+    // the number of (virtual) zeroes in front of the first digit in the first group
+    let mut index = first_power.rem_euclid(4) - 3;
+    for _ in 0..ndigits {
+      let mut digit = 0_u16;
+      for _ in 0..4 {
+        digit *= 10;
+        if index >= 0 && index < self.len {
+          digit += self.data[index] as u16;
+        }
+        index += 1;
+      }
+      result.extend_from_slice(&digit.to_be_bytes());
+    }
+
+    Ok(result)
+  }
+
+  pub(crate) fn from_postgres_numeric(bytes: &[u8]) -> Result<Sci, ConversionError> {
+    if bytes.len() < 8 {
+      return Err(ConversionError::ParseError);
+    }
+    let read = |pos: usize| u16::from_be_bytes([bytes[pos], bytes[pos + 1]]);
+    let ndigits = read(0) as i16;
+    let weight = read(2) as i16;
+    let sign = match read(4) {
+      NUMERIC_POS => Sign::POSITIVE,
+      NUMERIC_NEG => Sign::NEGATIVE,
+      NUMERIC_NAN | NUMERIC_PINF | NUMERIC_NINF => return Err(ConversionError::FloatIsNotFinite),
+      _ => return Err(ConversionError::ParseError),
+    };
+    if ndigits < 0 || bytes.len() != 8 + 2 * ndigits as usize {
+      return Err(ConversionError::ParseError);
+    }
+    if ndigits == 0 {
+      return Ok(Sci::ZERO);
+    }
+
+    let (result, mut result_ptr) = Builder::new(
+      sign,
+      4 * ndigits as isize,
+      (isize::from(weight) - isize::from(ndigits) + 1) * 4,
+    );
+    for pos in (8..bytes.len()).step_by(2) {
+      let mut digit = read(pos);
+      if digit > 9999 {
+        return Err(ConversionError::ParseError);
+      }
+      result_ptr = result_ptr.offset(4);
+      for i in 1..=4 {
+        result_ptr[-i] = (digit % 10) as i8;
+        digit /= 10;
+      }
+    }
+
+    Ok(result.finish())
+  }
+}
+
+#[cfg(feature = "postgres-types")]
+mod postgres_types_impl {
+  use crate::types::scientific::Scientific;
+  use alloc::boxed::Box;
+  use bytes::BytesMut;
+  use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+  use std::error::Error;
+
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgres-types")))]
+  impl ToSql for Scientific {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+      out.extend_from_slice(&self.to_postgres_numeric()?);
+      Ok(IsNull::No)
+    }
+
+    accepts!(NUMERIC);
+
+    to_sql_checked!();
+  }
+
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgres-types")))]
+  impl<'a> FromSql<'a> for Scientific {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+      Ok(Scientific::from_postgres_numeric(raw)?)
+    }
+
+    accepts!(NUMERIC);
+  }
+}
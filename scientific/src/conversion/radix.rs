@@ -0,0 +1,99 @@
+use crate::conversion::limbs::{
+  convert, digits_to_limbs, limbs_to_digits, Base, U64_TEN_POW_DIGITS,
+};
+use crate::types::builder::Builder;
+use crate::types::conversion_error::ConversionError;
+use crate::types::owner::Owner;
+use crate::types::ptr::Ptr;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// The maximum number of decimal digits of an integer which is converted into limbs.
+pub(crate) const MAX_DIGITS: isize = 100_000_000;
+
+impl Sci {
+  // the absolute value of the integer as little endian limbs in the base `to` (empty for zero)
+  fn to_limbs(&self, to: Base) -> Result<Vec<u64>, ConversionError> {
+    if self.is_zero() {
+      return Ok(Vec::new());
+    }
+    if self.exponent < 0 {
+      return Err(ConversionError::NumberIsNotAnInteger);
+    }
+    if self.exponent > MAX_DIGITS - self.len {
+      return Err(ConversionError::NumberTooLarge);
+    }
+    let mut digits = self.data.as_slice(self.len).to_vec();
+    digits.resize(digits.len() + self.exponent as usize, 0);
+    Ok(convert(
+      &digits_to_limbs(&digits, 10, U64_TEN_POW_DIGITS),
+      Base::DECIMAL,
+      to,
+    ))
+  }
+
+  pub(crate) fn to_le_limbs(&self) -> Result<Vec<u64>, ConversionError> {
+    self.to_limbs(Base::BINARY)
+  }
+
+  pub(crate) fn to_str_radix(&self, radix: u32) -> Result<String, ConversionError> {
+    assert_radix(radix);
+    let (base, digits_per_limb) = Base::radix(u64::from(radix));
+    let digits = limbs_to_digits(&self.to_limbs(base)?, u64::from(radix), digits_per_limb);
+    let mut result = String::with_capacity(digits.len() + 1);
+    if digits.is_empty() {
+      result.push('0');
+    } else if self.sign.is_negative() {
+      result.push('-');
+    }
+    for digit in digits {
+      result.push(char::from_digit(u32::from(digit), radix).unwrap_or_default());
+    }
+    Ok(result)
+  }
+
+  pub(crate) fn from_str_radix_integer(src: &str, radix: u32) -> Result<Sci, ConversionError> {
+    assert_radix(radix);
+    let (is_negative, src) = match src.as_bytes().first() {
+      Some(b'-') => (true, &src[1..]),
+      Some(b'+') => (false, &src[1..]),
+      _ => (false, src),
+    };
+    if src.is_empty() {
+      return Err(ConversionError::ParseError);
+    }
+    let digits = src
+      .chars()
+      .map(|c| c.to_digit(radix).map(|d| d as u8))
+      .collect::<Option<Vec<u8>>>()
+      .ok_or(ConversionError::ParseError)?;
+
+    let (base, digits_per_limb) = Base::radix(u64::from(radix));
+    let mantissa = limbs_to_digits(
+      &convert(
+        &digits_to_limbs(&digits, u64::from(radix), digits_per_limb),
+        base,
+        Base::DECIMAL,
+      ),
+      10,
+      U64_TEN_POW_DIGITS,
+    );
+    let len = mantissa.len() as isize;
+    Ok(Builder::from_data(
+      Sign::new(is_negative),
+      Ptr::new(mantissa.as_slice()),
+      len,
+      0,
+      Owner::new(mantissa),
+    ))
+  }
+}
+
+fn assert_radix(radix: u32) {
+  assert!(
+    (2..=36).contains(&radix),
+    "radix must be in the range 2..=36, but is {radix}"
+  );
+}
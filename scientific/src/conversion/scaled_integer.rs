@@ -0,0 +1,105 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::precision::Precision;
+use crate::types::rounding::Rounding;
+use crate::types::rounding_mode::RoundingMode;
+use crate::types::sci::Sci;
+
+// The maximum precision of the Arrow types `Decimal128` and `Decimal256`.
+const DECIMAL128_MAX_PRECISION: u8 = 38;
+const DECIMAL256_MAX_PRECISION: u8 = 76;
+
+impl Sci {
+  pub(crate) fn to_decimal128(
+    &self,
+    precision: u8,
+    scale: i8,
+    rounding: Rounding,
+  ) -> Result<i128, ConversionError> {
+    if precision > DECIMAL128_MAX_PRECISION {
+      return Err(ConversionError::PrecisionTooLarge);
+    }
+    let value = self.to_scaled(precision, scale, rounding)?;
+    let mut result = 0_i128;
+    value.scaled_digits(scale, |digit| result = result * 10 + i128::from(digit));
+    Ok(if value.sign.is_negative() {
+      -result
+    } else {
+      result
+    })
+  }
+
+  pub(crate) fn to_decimal256(
+    &self,
+    precision: u8,
+    scale: i8,
+    rounding: Rounding,
+  ) -> Result<[u64; 4], ConversionError> {
+    if precision > DECIMAL256_MAX_PRECISION {
+      return Err(ConversionError::PrecisionTooLarge);
+    }
+    let value = self.to_scaled(precision, scale, rounding)?;
+    let mut result = [0_u64; 4];
+    value.scaled_digits(scale, |digit| {
+      let mut carry = u128::from(digit);
+      for limb in result.iter_mut() {
+        let v = u128::from(*limb) * 10 + carry;
+        *limb = v as u64;
+        carry = v >> 64;
+      }
+    });
+    if value.sign.is_negative() {
+      negate(&mut result);
+    }
+    Ok(result)
+  }
+
+  pub(crate) fn from_scaled_i256(mut value: [u64; 4], scale: i8) -> Sci {
+    let is_negative = value[3] >> 63 != 0;
+    if is_negative {
+      negate(&mut value);
+    }
+
+    Sci::from_le_limbs(is_negative, &value, -isize::from(scale))
+  }
+
+  // round to `scale` decimals and check that the scaled integer does not exceed `precision` digits
+  fn to_scaled(
+    &self,
+    precision: u8,
+    scale: i8,
+    rounding: Rounding,
+  ) -> Result<Sci, ConversionError> {
+    let mut value = self.clone();
+    value.round_assign(
+      Precision::Decimals(isize::from(scale)),
+      RoundingMode::Rounding(rounding),
+    );
+    if !value.is_zero() && value.exponent0() + isize::from(scale) > isize::from(precision) {
+      return Err(ConversionError::NumberTooLarge);
+    }
+    Ok(value)
+  }
+
+  // calls `f` for every digit of the integer `self * 10^scale` (which must be an integer)
+  fn scaled_digits<F: FnMut(u8)>(&self, scale: i8, mut f: F) {
+    if self.is_zero() {
+      return;
+    }
+    for i in 0..self.len {
+      f(self.data[i] as u8);
+    }
+    for _ in 0..self.exponent + isize::from(scale) {
+      f(0);
+    }
+  }
+}
+
+// two's complement of a little endian 256-bit integer
+fn negate(value: &mut [u64; 4]) {
+  let mut carry = true;
+  for limb in value.iter_mut() {
+    let (v, c) = (!*limb).overflowing_add(u64::from(carry));
+    *limb = v;
+    carry = c;
+  }
+}
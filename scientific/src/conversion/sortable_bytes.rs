@@ -0,0 +1,159 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::owner::Owner;
+use crate::types::ptr::Ptr;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+use alloc::vec::Vec;
+
+// Format:
+// - one byte with the sign: NEGATIVE, ZERO or POSITIVE (zero has nothing else)
+// - the exponent0, see `push_exponent`
+// - the mantissa, two digits per byte, each digit as 1..=10 and terminated by a 0
+// For negative numbers the exponent and mantissa are inverted.
+// Each part is prefix free and thus the order of the bytes is the same as of the numbers.
+const NEGATIVE: u8 = 0x01;
+const ZERO: u8 = 0x02;
+const POSITIVE: u8 = 0x03;
+
+impl Sci {
+  pub(crate) fn to_sortable_bytes(&self) -> Vec<u8> {
+    if self.is_zero() {
+      return vec![ZERO];
+    }
+    let mut result = Vec::with_capacity(self.len as usize / 2 + 11);
+    result.push(if self.sign.is_negative() {
+      NEGATIVE
+    } else {
+      POSITIVE
+    });
+    push_exponent(&mut result, self.exponent0());
+    let mut p = self.data;
+    let mut len = self.len;
+    while len >= 2 {
+      let a = *p as u8 + 1;
+      p.inc();
+      let b = *p as u8 + 1;
+      p.inc();
+      result.push((a << 4) | b);
+      len -= 2;
+    }
+    if len == 1 {
+      result.push((*p as u8 + 1) << 4);
+    } else {
+      result.push(0);
+    }
+    if self.sign.is_negative() {
+      for b in result[1..].iter_mut() {
+        *b = !*b;
+      }
+    }
+    result
+  }
+
+  pub(crate) fn from_sortable_bytes(bytes: &[u8]) -> Result<Sci, ConversionError> {
+    let (&prefix, bytes) = bytes.split_first().ok_or(ConversionError::ParseError)?;
+    let invert = match prefix {
+      ZERO if bytes.is_empty() => return Ok(Sci::ZERO),
+      NEGATIVE => 0xff,
+      POSITIVE => 0,
+      _ => return Err(ConversionError::ParseError),
+    };
+    let mut it = bytes.iter().map(|b| b ^ invert);
+
+    let exponent0 = read_exponent(&mut it)?;
+
+    let mut owned = Vec::with_capacity(bytes.len() * 2);
+    loop {
+      let b = it.next().ok_or(ConversionError::ParseError)?;
+      let (high, low) = (b >> 4, b & 15);
+      if high == 0 {
+        if low != 0 {
+          return Err(ConversionError::ParseError);
+        }
+        break;
+      }
+      if high > 10 {
+        return Err(ConversionError::ParseError);
+      }
+      owned.push(high - 1);
+      if low == 0 {
+        break;
+      }
+      if low > 10 {
+        return Err(ConversionError::ParseError);
+      }
+      owned.push(low - 1);
+    }
+    if it.next().is_some() {
+      return Err(ConversionError::ParseError);
+    }
+
+    let len = owned.len() as isize;
+    let data = Ptr::new(owned.as_slice());
+    if len == 0 || *data == 0 || data[len - 1] == 0 {
+      return Err(ConversionError::ParseError);
+    }
+    Ok(Sci {
+      sign: Sign::new(invert != 0),
+      data,
+      len,
+      exponent: exponent0
+        .checked_sub(len)
+        .ok_or(ConversionError::ExponentTooLargeForThisPlatform)?,
+      owner: Owner::new(owned),
+    })
+  }
+}
+
+// The exponent is encoded as:
+// - 0x40..=0xbf: the exponent -64..=63
+// - 0xc1..=0xc8: 1 to 8 bytes (big endian) of exponent-64 follow
+// - 0x37..=0x3e: 8 to 1 bytes (big endian, inverted) of -65-exponent follow
+// In both cases with multiple bytes the first byte must not be zero.
+fn push_exponent(result: &mut Vec<u8>, exponent: isize) {
+  let exponent = exponent as i128;
+  if (-64..64).contains(&exponent) {
+    result.push((exponent + 0x80) as u8);
+  } else {
+    let (value, invert) = if exponent >= 64 {
+      ((exponent - 64) as u64, 0)
+    } else {
+      ((-65 - exponent) as u64, 0xff)
+    };
+    let len = (8 - value.leading_zeros() as usize / 8).max(1);
+    result.push(if invert == 0 {
+      0xc0 + len as u8
+    } else {
+      0x3f - len as u8
+    });
+    result.extend(value.to_be_bytes()[8 - len..].iter().map(|b| b ^ invert));
+  }
+}
+
+fn read_exponent<I: Iterator<Item = u8>>(it: &mut I) -> Result<isize, ConversionError> {
+  let header = it.next().ok_or(ConversionError::ParseError)?;
+  let exponent = match header {
+    0x40..=0xbf => i128::from(header) - 0x80,
+    0xc1..=0xc8 => 64 + i128::from(read_value(it, header - 0xc0, 0)?),
+    0x37..=0x3e => -65 - i128::from(read_value(it, 0x3f - header, 0xff)?),
+    _ => return Err(ConversionError::ParseError),
+  };
+  isize::try_from(exponent).map_err(|_| ConversionError::ExponentTooLargeForThisPlatform)
+}
+
+fn read_value<I: Iterator<Item = u8>>(
+  it: &mut I,
+  len: u8,
+  invert: u8,
+) -> Result<u64, ConversionError> {
+  let mut value = 0;
+  for i in 0..len {
+    let b = it.next().ok_or(ConversionError::ParseError)? ^ invert;
+    if i == 0 && b == 0 && len > 1 {
+      // not the shortest encoding
+      return Err(ConversionError::ParseError);
+    }
+    value = (value << 8) | u64::from(b);
+  }
+  Ok(value)
+}
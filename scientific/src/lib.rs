@@ -41,6 +41,25 @@
 //!
 //! The functions [`Scientific::to_bytes`](crate::Scientific::to_bytes) and [`Scientific::from_bytes`](crate::Scientific::from_bytes) use a compressed representation and not ASCII
 //! (this format will also be used when using serde with non human-readable formats).
+//! With [`Scientific::encode_into`](crate::Scientific::encode_into) the compressed representation is prefixed by its length, to embed it in a stream of bytes.
+//! For interoperability [`Scientific::to_cbor_decimal`](crate::Scientific::to_cbor_decimal) and [`Scientific::from_cbor_decimal`](crate::Scientific::from_cbor_decimal)
+//! use the CBOR decimal fraction (tag 4).
+//!
+//! The function [`Scientific::to_sortable_bytes`](crate::Scientific::to_sortable_bytes) creates a binary representation whose lexicographic order
+//! is the same as the order of the numbers (e.g. to use them as keys in a key-value store).
+//!
+//! The functions [`Scientific::to_decimal128`](crate::Scientific::to_decimal128) and [`Scientific::from_scaled_i128`](crate::Scientific::from_scaled_i128)
+//! (and their 256-bit variants) convert from/to scaled integers as used by the Arrow types `Decimal128` and `Decimal256`.
+//! There are also conversions from/to the BSON `Decimal128` ([`Scientific::to_bson_decimal128`](crate::Scientific::to_bson_decimal128))
+//! and the protobuf `google.type.Decimal` ([`Scientific::to_google_decimal`](crate::Scientific::to_google_decimal)).
+//!
+//! Integers can be converted from/to binary limbs ([`Scientific::to_le_limbs`](crate::Scientific::to_le_limbs))
+//! and strings in any radix from 2 to 36 ([`Scientific::to_str_radix`](crate::Scientific::to_str_radix)),
+//! this uses a subquadratic algorithm so even numbers with millions of digits convert quickly.
+//! Hexadecimal floating-point literals (`0x1.8p+3`) are converted exactly with
+//! [`Scientific::from_hex_float`](crate::Scientific::from_hex_float) and [`Scientific::to_hex_float`](crate::Scientific::to_hex_float).
+//! Every value is a fraction: [`Scientific::to_fraction`](crate::Scientific::to_fraction) returns it in lowest terms (`0.125` is `1/8`)
+//! and [`Scientific::best_rational`](crate::Scientific::best_rational) finds the closest fraction with a limited denominator.
 //!
 //! # Precision
 //!
@@ -58,6 +77,21 @@
 //! Shortcuts: [`Precision::INTEGER`](crate::Precision::INTEGER) for integer calculations (aka `Decimals(0)`) and [`Precision::F64`](crate::Precision::F64) for
 //! calculations with a slightly better precision as an f64 (aka `Digits(16)`).
 //!
+//! The operators (`+`, `-`, `*`, `/` and `%`) are implemented for references and values, the latter reuse the mantissa
+//! of an operand if possible. They are also implemented with primitive integers (`&a + 1`, `2 * a`), which can be
+//! compared with values, too (`a > 0`). The same is true for floats: the operators convert them like `TryFrom<f64>`
+//! (`0.1` is `0.1`) and **panic if the float is not finite** (NaN or infinite), comparisons use the exact binary value
+//! like `Scientific::from_f64_exact` (`0.1_f32` is `0.100000001490116119384765625`), NaN is unordered.
+//! `+=` and `-=` write into the mantissa if it is not shared, [`Accumulator`](crate::Accumulator) keeps spare room around the
+//! mantissa for the fast summation of many values.
+//! Sums are exact, [`Scientific::sum_rounded`](crate::Scientific::sum_rounded) and [`Scientific::dot_rounded`](crate::Scientific::dot_rounded) round only the final result.
+//! [`Polynomial`](crate::Polynomial) evaluates polynomials and finds their real roots, correctly rounded to a precision.
+//!
+//! The division operator (`/`) truncates the result to the precision [`Precision::F64`](crate::Precision::F64), use [`Scientific::div_truncate`](crate::Scientific::div_truncate)
+//! or [`Scientific::div_rpsp`](crate::Scientific::div_rpsp) for other precisions.
+//! The remainder operator (`%`) has the sign of the dividend, [`Scientific::rem_euclid`](crate::Scientific::rem_euclid) is never negative
+//! and [`Scientific::mod_floor`](crate::Scientific::mod_floor) has the sign of the divisor (like `%` in Python).
+//!
 //! # Shifting
 //!
 //! The shifting operators do shift by one digit (and not one bit as you may expected).
@@ -95,6 +129,10 @@
 //! # Features
 //!
 //! - `serde`: Enable De-/Serialization with serde.
+//!   Human-readable formats use a string, when deserializing also numbers are accepted.
+//!   The module [`serde`](crate::serde) contains adapters to change the representation per field, e.g.
+//!   [`as_number`](crate::serde::as_number) to use a JSON number (only supported by `serde_json` with `arbitrary_precision`)
+//!   or [`as_bytes`](crate::serde::as_bytes) to use the compressed format also for human-readable formats.
 //!
 //! - `macro`: Re-export the [`Scientific!`](macro@crate::Scientific) macro, enabled by default.
 //!
@@ -107,6 +145,21 @@
 //!
 //! - `debug`: Enables several checks. Very helpful during development of this lib.
 //!
+//! - `postgres`: Enable conversion from/to the binary format of the Postgres `NUMERIC` type with
+//!   [`to_postgres_numeric`](crate::Scientific::to_postgres_numeric) and [`from_postgres_numeric`](crate::Scientific::from_postgres_numeric).
+//!
+//! - `postgres-types`: Implement `ToSql` and `FromSql` of `postgres-types` for the `NUMERIC` type
+//!   (enables `postgres` and `std`).
+//!
+//! - `num-traits`: Implement the traits of `num-traits`, e.g. `Num`, `Signed`, `FromPrimitive`, `ToPrimitive`,
+//!   `CheckedDiv` and `Euclid`. Division uses the precision [`Precision::F64`](crate::Precision::F64) (like the operator `/`).
+//!
+//! - `num-bigint`: Conversion from/to `BigInt` and `BigUint` of `num-bigint` (integers with at most 100000000 digits).
+//!
+//! - `rust_decimal`: Conversion from/to `Decimal` of `rust_decimal`.
+//!
+//! - `bigdecimal`: Conversion from/to `BigDecimal` of `bigdecimal` (enables `num-bigint`).
+//!
 //! # Exponent
 //!
 //! The exponent is represented as an [`isize`](::core::isize). It is expected that it will never under-/overflow,
@@ -117,11 +170,15 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(not(no_re_export))]
+pub use crate::types::accumulator::Accumulator;
 #[cfg(not(no_re_export))]
 pub use crate::types::conversion_error::ConversionError;
 #[cfg(not(no_re_export))]
 pub use crate::types::error::Error;
 #[cfg(not(no_re_export))]
+pub use crate::types::polynomial::Polynomial;
+#[cfg(not(no_re_export))]
 pub use crate::types::precision::Precision::{self, Decimals, Digits};
 #[cfg(not(no_re_export))]
 pub use crate::types::rounding::Rounding::{
@@ -134,6 +191,20 @@ pub use crate::types::scientific::Scientific;
 #[cfg(all(not(no_re_export), feature = "macro"))]
 pub use scientific_macro::Scientific;
 
+/// Adapters for De-/Serialization with serde.
+///
+/// Use them with `#[serde(with = "...")]` on a field of type [`Scientific`](struct@crate::Scientific).
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(all(not(no_re_export), feature = "serde"))]
+pub mod serde {
+  pub use crate::types::serde_as_bytes as as_bytes;
+  pub use crate::types::serde_as_f64 as as_f64;
+  pub use crate::types::serde_as_fixed as as_fixed;
+  pub use crate::types::serde_as_number as as_number;
+  pub use crate::types::serde_as_string as as_string;
+  pub use crate::types::serde_exact_float as exact_float;
+}
+
 pub(crate) mod conversion;
 pub(crate) mod math;
 pub(crate) mod types;
@@ -0,0 +1,147 @@
+use crate::types::builder::Builder;
+use crate::types::owner::Owner;
+use crate::types::ptr::Ptr;
+use crate::types::sci::Sci;
+use alloc::vec::Vec;
+use core::mem::replace;
+
+impl Sci {
+  // self += rhs if the result can be written into the data of self, which requires that the data
+  // is not shared and large enough; returns false (without changing self) otherwise
+  pub(crate) fn try_add_assign(&mut self, rhs: &Sci) -> bool {
+    if rhs.is_zero() {
+      true
+    } else if self.is_zero() {
+      *self = rhs.clone();
+      true
+    } else {
+      self.nz_add_in_place(rhs)
+    }
+  }
+
+  // self += rhs, the data of self is grown if necessary (see reserve)
+  pub(crate) fn add_assign(&mut self, rhs: &Sci, spare: isize) {
+    if !self.try_add_assign(rhs) {
+      // one more digit in front for a possible carry
+      let head = (rhs.exponent0() - self.exponent0()).max(0) + 1;
+      let tail = (self.exponent - rhs.exponent).max(0);
+      self.reserve(head + spare, tail + spare);
+      let done = self.nz_add_in_place(rhs);
+      debug_assert!(done);
+    }
+  }
+
+  // ensure that the data is not shared and has room for at least head digits in front and tail
+  // digits after it, self must not be zero
+  pub(crate) fn reserve(&mut self, head: isize, tail: isize) {
+    if let Some((current_head, current_tail)) = self.room() {
+      if current_head >= head && current_tail >= tail {
+        return;
+      }
+      // move the digits within the owned vec, which is grown if necessary
+      let vec = self.owner.vec_mut().expect("the data is not shared");
+      let len = (head + self.len + tail) as usize;
+      if len > vec.len() {
+        vec.reserve(len - vec.len());
+        vec.resize(len, 0);
+      }
+      let start = current_head as usize;
+      vec.copy_within(start..start + self.len as usize, head as usize);
+      self.data = Ptr::new_mut(vec.as_mut_slice()).offset(head);
+    } else {
+      let mut vec = vec![0; (head + self.len + tail) as usize];
+      vec[head as usize..(head + self.len) as usize].copy_from_slice(self.data.as_slice(self.len));
+      self.data = Ptr::new_mut(vec.as_mut_slice()).offset(head);
+      self.owner = Owner::new(vec);
+    }
+  }
+
+  // the number of digits in the data before and after the value,
+  // or None if the data is shared or not owned
+  fn room(&mut self) -> Option<(isize, isize)> {
+    let vec = self.owner.vec_mut()?;
+    let buffer = Ptr::new_mut(vec.as_mut_slice());
+    let buffer_end = buffer.offset(vec.len() as isize);
+    if self.data < buffer || self.data.offset(self.len) > buffer_end {
+      // the data is not part of the owned vec
+      return None;
+    }
+    let head = self.data.offset_from(buffer);
+    // use the pointer derived from the mutable vec for writing
+    self.data = buffer.offset(head);
+    Some((head, vec.len() as isize - head - self.len))
+  }
+
+  fn nz_add_in_place(&mut self, rhs: &Sci) -> bool {
+    let exponent0 = self.exponent0();
+    let low = self.exponent.min(rhs.exponent);
+    let high = exponent0.max(rhs.exponent0());
+    // the result is written to data[start..end] (start may be negative)
+    let start = exponent0 - high;
+    let end = exponent0 - low;
+
+    let Some((head, tail)) = self.room() else {
+      return false;
+    };
+    if start < -head || end > self.len + tail {
+      return false;
+    }
+    let mut data = self.data;
+    for i in (start..0).chain(self.len..end) {
+      data[i] = 0;
+    }
+
+    let subtract = self.sign != rhs.sign;
+    // calculate |rhs| - |self| instead of |self| - |rhs| if the result would be negative
+    let swap = subtract && self.compare::<false>(rhs).is_lt();
+    let rhs_start = exponent0 - rhs.exponent0();
+    let mut carry = 0;
+    for i in (start..end).rev() {
+      let lhs_digit = data[i];
+      let rhs_digit = if (rhs_start..rhs_start + rhs.len).contains(&i) {
+        rhs.data[i - rhs_start]
+      } else {
+        0
+      };
+      let mut value = if !subtract {
+        lhs_digit + rhs_digit + carry
+      } else if swap {
+        rhs_digit - lhs_digit - carry
+      } else {
+        lhs_digit - rhs_digit - carry
+      };
+      carry = 0;
+      if value >= 10 {
+        value -= 10;
+        carry = 1;
+      } else if value < 0 {
+        value += 10;
+        carry = 1;
+      }
+      data[i] = value;
+    }
+
+    let sign = if swap { rhs.sign } else { self.sign };
+    let owner = replace(&mut self.owner, Owner::None);
+    *self = if carry == 0 {
+      Builder::from_data(sign, data.offset(start), end - start, low, owner)
+    } else if start > -head {
+      data[start - 1] = 1;
+      Builder::from_data(sign, data.offset(start - 1), end - start + 1, low, owner)
+    } else {
+      // there is no room for the carry
+      let mut vec = Vec::with_capacity((end - start + 1) as usize);
+      vec.push(1);
+      vec.extend_from_slice(data.offset(start).as_slice(end - start));
+      let len = vec.len() as isize;
+      Builder::from_data(
+        sign,
+        Ptr::new_mut(vec.as_mut_slice()),
+        len,
+        low,
+        Owner::new(vec),
+      )
+    };
+    true
+  }
+}
@@ -0,0 +1,28 @@
+use crate::types::error::Error;
+use crate::types::sci::Sci;
+
+impl Sci {
+  // the integer quotient and the remainder with 0 <= remainder < |rhs|
+  pub(crate) fn div_rem_euclid(&self, rhs: &Sci) -> Result<(Sci, Sci), Error> {
+    let (quot, rem) = self.div_rem(rhs)?;
+    if rem.sign.is_negative() && !rem.is_zero() {
+      if rhs.sign.is_negative() {
+        Ok((quot.add(&Sci::ONE), rem.sub(rhs)))
+      } else {
+        Ok((quot.sub(&Sci::ONE), rem.add(rhs)))
+      }
+    } else {
+      Ok((quot, rem))
+    }
+  }
+
+  // the integer quotient rounded towards negative infinity and the remainder with the sign of rhs
+  pub(crate) fn div_rem_floor(&self, rhs: &Sci) -> Result<(Sci, Sci), Error> {
+    let (quot, rem) = self.div_rem(rhs)?;
+    if !rem.is_zero() && rem.sign != rhs.sign {
+      Ok((quot.sub(&Sci::ONE), rem.add(rhs)))
+    } else {
+      Ok((quot, rem))
+    }
+  }
+}
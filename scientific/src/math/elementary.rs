@@ -0,0 +1,181 @@
+use crate::types::error::Error;
+use crate::types::precision::Precision;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+use crate::types::sign::Sign;
+
+const MANTISSA_10: [u8; 2] = [1, 0];
+const TEN: Sci = Sci::nz_unchecked_static_new(Sign::POSITIVE, &MANTISSA_10, 0);
+
+// Additional digits used for all intermediate results.
+pub(crate) const GUARD: isize = 10;
+// ln takes this many square roots before using its series, exp squares its result this often.
+// Both lose less than 4 digits by doing so (2^11 < 10^4).
+const REDUCTIONS: usize = 10;
+const REDUCTION_DIGITS: isize = 4;
+
+// pi with the given number of decimals by Machin's formula: pi = 16 atan(1/5) - 4 atan(1/239)
+pub(crate) fn pi(decimals: isize) -> Sci {
+  let decimals = decimals + GUARD;
+  let mut result = atan_inv(5, decimals)
+    .mul(&Scientific::from(16_u8).inner)
+    .sub(&atan_inv(239, decimals).mul(&Scientific::from(4_u8).inner));
+  result.truncate_assign(Precision::Decimals(decimals));
+  result
+}
+
+// sin(pi x) with the given number of (significant) digits, x must not be an integer
+pub(crate) fn sin_pi(x: &Sci, digits: isize) -> Sci {
+  // sin(pi x) has the period 2 and sin(pi (1 - t)) = sin(pi t), which moves x to 0 < t <= 1/2
+  let (_, mut t) = x
+    .div_rem(&Scientific::from(2_u8).inner)
+    .expect("2 is not zero");
+  let mut sign = t.sign;
+  t.sign = Sign::POSITIVE;
+  if t.compare::<true>(&Sci::ONE).is_ge() {
+    t = t.sub(&Sci::ONE);
+    sign = !sign;
+  }
+  if t.compare::<true>(&Sci::POINT5).is_gt() {
+    t = Sci::ONE.sub(&t);
+  }
+
+  // sin(z) = z - z^3 / 3! + z^5 / 5! - ... with z = pi t, the result is about z
+  let decimals = digits + GUARD - t.exponent0();
+  let precision = Precision::Decimals(decimals);
+  let mut z = pi(decimals).mul(&t);
+  z.truncate_assign(precision);
+  let mut z2 = z.mul(&z);
+  z2.truncate_assign(precision);
+  let mut term = z.clone();
+  let mut result = z;
+  for k in (2_usize..).step_by(2) {
+    term = term
+      .mul(&z2)
+      .div(&Scientific::from(k * (k + 1)).inner, precision, false)
+      .expect("k is not zero");
+    if term.is_zero() {
+      break;
+    }
+    term.neg_assign();
+    result = result.add(&term);
+  }
+  result.sign = sign;
+  result
+}
+
+// atan(1/n) = 1/n - 1/(3 n^3) + 1/(5 n^5) - ...
+fn atan_inv(n: u8, decimals: isize) -> Sci {
+  let precision = Precision::Decimals(decimals);
+  let n = Scientific::from(n).inner;
+  let n2 = n.mul(&n);
+  let mut power = Sci::ONE.div(&n, precision, false).expect("n is not zero");
+  let mut result = power.clone();
+  for k in (3_usize..).step_by(2) {
+    power = power.div(&n2, precision, false).expect("n is not zero");
+    if power.is_zero() {
+      break;
+    }
+    let mut term = power
+      .div(&Scientific::from(k).inner, precision, false)
+      .expect("k is not zero");
+    if k % 4 == 3 {
+      term.neg_assign();
+    }
+    result = result.add(&term);
+  }
+  result
+}
+
+// ln(x) with the given number of decimals, x must be positive
+pub(crate) fn ln(x: &Sci, decimals: isize) -> Sci {
+  // ln(m 10^e) = ln(m) + e ln(10) with 1 <= m < 10
+  let exponent = x.exponent1();
+  let mut mantissa = x.clone();
+  mantissa.exponent -= exponent;
+  let mut result = ln_mantissa(&mantissa, decimals + GUARD);
+  if exponent != 0 {
+    let exponent = Scientific::from(exponent).inner;
+    result = result.add(&ln_mantissa(&TEN, decimals + GUARD + exponent.exponent0()).mul(&exponent));
+  }
+  result.truncate_assign(Precision::Decimals(decimals));
+  result
+}
+
+// ln(x) for 1 <= x <= 10
+fn ln_mantissa(x: &Sci, decimals: isize) -> Sci {
+  let precision = Precision::Decimals(decimals + REDUCTION_DIGITS);
+  // ln(x) = 2^r ln(x^(1/2^r)), which moves the argument close to 1
+  let mut y = x.clone();
+  for _ in 0..REDUCTIONS {
+    y = y
+      .sqrt(Precision::Digits(decimals + REDUCTION_DIGITS + 1), false)
+      .expect("y is positive");
+  }
+  // ln(y) = 2 atanh(t) = 2 (t + t^3 / 3 + t^5 / 5 + ...) with t = (y - 1) / (y + 1)
+  let t = y
+    .sub(&Sci::ONE)
+    .div(&y.add(&Sci::ONE), precision, false)
+    .expect("y + 1 is positive");
+  let mut t2 = t.mul(&t);
+  t2.truncate_assign(precision);
+  let mut power = t.clone();
+  let mut result = t;
+  for k in (3_usize..).step_by(2) {
+    power = power.mul(&t2);
+    power.truncate_assign(precision);
+    if power.is_zero() {
+      break;
+    }
+    let term = power
+      .div(&Scientific::from(k).inner, precision, false)
+      .expect("k is not zero");
+    result = result.add(&term);
+  }
+  result.mul(&Scientific::from(1_u32 << (REDUCTIONS + 1)).inner)
+}
+
+// e^x with the given number of (significant) digits, fails if the exponent of the result
+// doesn't fit into an isize
+pub(crate) fn exp(x: &Sci, digits: isize) -> Result<Sci, Error> {
+  // e^x = 10^q e^r with q = trunc(x / ln(10)) and |r| < ln(10)
+  let (q, r) = if x.compare::<false>(&Scientific::from(2_u8).inner).is_lt() {
+    (Sci::ZERO, x.clone())
+  } else {
+    let ln10 = ln_mantissa(&TEN, digits + GUARD + x.exponent0());
+    let q = x
+      .div(&ln10, Precision::INTEGER, false)
+      .expect("ln(10) is not zero");
+    let r = x.sub(&q.mul(&ln10));
+    (q, r)
+  };
+
+  // e^r = (e^(r / 2^s))^(2^s) with a series for the smaller argument
+  let precision = Precision::Decimals(digits + GUARD + REDUCTION_DIGITS);
+  let mut s = r.mul(&Sci::POINT5.powi(REDUCTIONS));
+  s.truncate_assign(precision);
+  let mut result = Sci::ONE;
+  let mut term = Sci::ONE;
+  for k in 1_usize.. {
+    term = term
+      .mul(&s)
+      .div(&Scientific::from(k).inner, precision, false)
+      .expect("k is not zero");
+    if term.is_zero() {
+      break;
+    }
+    result = result.add(&term);
+  }
+  for _ in 0..REDUCTIONS {
+    result = result.mul(&result);
+    result.truncate_assign(Precision::Digits(digits + GUARD));
+  }
+
+  let q = isize::try_from(&Scientific { inner: q }).map_err(|_| Error::NumberTooLarge)?;
+  result.exponent = result
+    .exponent
+    .checked_add(q)
+    .filter(|exponent| exponent.checked_add(result.len).is_some())
+    .ok_or(Error::NumberTooLarge)?;
+  Ok(result)
+}
@@ -0,0 +1,86 @@
+use crate::conversion::limbs::{mul, mul_add_assign, Base};
+use crate::types::sci::Sci;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// Products of at most this many words are multiplied one by one.
+const PRODUCT_THRESHOLD: usize = 16;
+// Binomial coefficients with a larger k are computed from their prime factorization if n is
+// at most SIEVE_LIMIT, the others by dividing the product n * (n - 1) * ... by k!.
+const SMALL_K: usize = 64;
+const SIEVE_LIMIT: usize = 1 << 22;
+
+impl Sci {
+  pub(crate) fn factorial(n: usize) -> Sci {
+    Sci::from_le_limbs(false, &product(2..=n as u64), 0)
+  }
+
+  pub(crate) fn binomial(n: usize, k: usize) -> Sci {
+    if k > n {
+      return Sci::ZERO;
+    }
+    let k = k.min(n - k);
+    if k < SMALL_K || n > SIEVE_LIMIT {
+      let numerator = Sci::from_le_limbs(false, &product((n - k + 1) as u64..=n as u64), 0);
+      return numerator
+        .div_rem(&Sci::factorial(k))
+        .expect("k! is not zero")
+        .0;
+    }
+
+    // Legendre's formula: the exponent of p in n! is n / p + n / p^2 + ...
+    let legendre = |n: usize, p: usize| {
+      let mut exponent = 0;
+      let mut power = n / p;
+      while power > 0 {
+        exponent += power;
+        power /= p;
+      }
+      exponent
+    };
+    let mut sieve = vec![true; n + 1];
+    let mut factors = Vec::new();
+    for p in 2..=n {
+      if !sieve[p] {
+        continue;
+      }
+      for multiple in (p.saturating_mul(p)..=n).step_by(p) {
+        sieve[multiple] = false;
+      }
+      let exponent = legendre(n, p) - legendre(k, p) - legendre(n - k, p);
+      factors.extend(core::iter::repeat(p as u64).take(exponent));
+    }
+    Sci::from_le_limbs(false, &product(factors.into_iter()), 0)
+  }
+}
+
+// The product of all factors as little endian binary limbs, computed with a product tree
+// so that the large multiplications are balanced.
+fn product<I: Iterator<Item = u64>>(factors: I) -> Vec<u64> {
+  // first collect as many factors as fit into one word
+  let mut words = Vec::new();
+  let mut word = 1_u64;
+  for factor in factors {
+    if let Some(value) = word.checked_mul(factor) {
+      word = value;
+    } else {
+      words.push(word);
+      word = factor;
+    }
+  }
+  words.push(word);
+  product_tree(&words)
+}
+
+fn product_tree(words: &[u64]) -> Vec<u64> {
+  if words.len() <= PRODUCT_THRESHOLD {
+    let mut result = vec![1];
+    for word in words {
+      mul_add_assign(&mut result, *word, 0);
+    }
+    result
+  } else {
+    let (low, high) = words.split_at(words.len() / 2);
+    mul(&product_tree(low), &product_tree(high), Base::BINARY)
+  }
+}
@@ -0,0 +1,103 @@
+use crate::types::precision::Precision;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+use alloc::vec::Vec;
+
+impl Sci {
+  // numerator and denominator in lowest terms, the denominator is positive
+  pub(crate) fn to_fraction(&self) -> (Sci, Sci) {
+    if self.exponent >= 0 || self.is_zero() {
+      return (self.clone(), Sci::ONE);
+    }
+    let mut numerator = self.clone();
+    numerator.exponent = 0;
+    let denominator = Sci::one(Sign::POSITIVE, -self.exponent);
+    let gcd = numerator
+      .gcd(&denominator)
+      .expect("numerator and denominator are integers");
+    (exact_div(&numerator, &gcd), exact_div(&denominator, &gcd))
+  }
+
+  pub(crate) fn continued_fraction(&self, max_terms: usize) -> Vec<Sci> {
+    let (mut numerator, mut denominator) = self.to_fraction();
+    let mut result = Vec::new();
+    while result.len() < max_terms && !denominator.is_zero() {
+      let (term, rem) = div_floor(&numerator, &denominator);
+      result.push(term);
+      numerator = denominator;
+      denominator = rem;
+    }
+    result
+  }
+
+  // the closest fraction with a denominator not larger than max_denominator (at least 1)
+  pub(crate) fn best_rational(&self, max_denominator: &Sci) -> (Sci, Sci) {
+    let (numerator, denominator) = self.to_fraction();
+    let mut max_denominator = max_denominator.clone();
+    max_denominator.truncate_assign(Precision::INTEGER);
+    if max_denominator.compare::<true>(&Sci::ONE).is_lt() {
+      max_denominator = Sci::ONE;
+    }
+    if denominator.compare::<true>(&max_denominator).is_le() {
+      return (numerator, denominator);
+    }
+
+    // the convergents p0/q0 and p1/q1 of the continued fraction, until the next denominator
+    // would exceed max_denominator (this happens before the end of the continued fraction)
+    let (mut p0, mut q0, mut p1, mut q1) = (Sci::ZERO, Sci::ONE, Sci::ONE, Sci::ZERO);
+    let (mut n, mut d) = (numerator.clone(), denominator.clone());
+    loop {
+      let (term, rem) = div_floor(&n, &d);
+      let q2 = q0.add(&term.mul(&q1));
+      if q2.compare::<true>(&max_denominator).is_gt() {
+        break;
+      }
+      let p2 = p0.add(&term.mul(&p1));
+      p0 = p1;
+      q0 = q1;
+      p1 = p2;
+      q1 = q2;
+      n = d;
+      d = rem;
+    }
+
+    // the best semiconvergent below max_denominator and the last convergent are the candidates
+    let (k, _) = div_floor(&max_denominator.sub(&q0), &q1);
+    let p_semi = p0.add(&k.mul(&p1));
+    let q_semi = q0.add(&k.mul(&q1));
+    // |p/q - n/d| = |p * d - n * q| / (q * d), the common factor 1/d is omitted
+    let distance = |p: &Sci, q: &Sci| {
+      let mut result = p.mul(&denominator).sub(&numerator.mul(q));
+      result.sign = Sign::POSITIVE;
+      result
+    };
+    let ordering = distance(&p1, &q1)
+      .mul(&q_semi)
+      .compare::<true>(&distance(&p_semi, &q_semi).mul(&q1))
+      .then_with(|| q1.compare::<true>(&q_semi));
+    if ordering.is_le() {
+      (p1, q1)
+    } else {
+      (p_semi, q_semi)
+    }
+  }
+}
+
+// the quotient rounded towards negative infinity and the remainder (for a positive divisor)
+fn div_floor(numerator: &Sci, denominator: &Sci) -> (Sci, Sci) {
+  let (mut quot, mut rem) = numerator
+    .div_rem(denominator)
+    .expect("denominator is not zero");
+  if rem.sign.is_negative() && !rem.is_zero() {
+    quot = quot.sub(&Sci::ONE);
+    rem = rem.add(denominator);
+  }
+  (quot, rem)
+}
+
+fn exact_div(numerator: &Sci, denominator: &Sci) -> Sci {
+  numerator
+    .div_rem(denominator)
+    .expect("denominator is not zero")
+    .0
+}
@@ -0,0 +1,178 @@
+use crate::math::elementary::{exp, ln, pi, sin_pi, GUARD};
+use crate::types::error::Error;
+use crate::types::precision::Precision;
+use crate::types::rounding_mode::RoundingMode;
+use crate::types::rounding_rpsp::RPSP;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+use crate::types::sign::Sign;
+use alloc::vec::Vec;
+
+// Positive integers up to this value are computed with factorial.
+const FACTORIAL_LIMIT: usize = 1000;
+
+impl Sci {
+  pub(crate) fn gamma(&self, precision: Precision) -> Result<Sci, Error> {
+    check_pole(self)?;
+    if let Ok(n) = usize::try_from(&Scientific {
+      inner: self.clone(),
+    }) {
+      if n <= FACTORIAL_LIMIT {
+        let mut result = Sci::factorial(n - 1);
+        result.round_assign(precision, RoundingMode::RPSP(RPSP));
+        return Ok(result);
+      }
+    }
+
+    let digits = match precision {
+      Precision::Digits(digits) => digits,
+      Precision::Decimals(decimals) => {
+        // the magnitude of the result is needed to know how many digits are required
+        let (estimate, sign) = ln_gamma(self, 1);
+        match exp(&estimate, 1) {
+          Ok(estimate) => decimals + estimate.exponent0(),
+          Err(_) if estimate.sign.is_negative() => {
+            // the result is far below the requested decimals
+            let mut result = Sci::one(sign, (-decimals).saturating_sub(GUARD));
+            result.round_assign(precision, RoundingMode::RPSP(RPSP));
+            return Ok(result);
+          }
+          Err(err) => return Err(err),
+        }
+      }
+    }
+    .max(1)
+      + GUARD;
+    let (ln, sign) = ln_gamma(self, digits);
+    let mut result = exp(&ln, digits)?;
+    result.sign = sign;
+    result.round_assign(precision, RoundingMode::RPSP(RPSP));
+    Ok(result)
+  }
+
+  pub(crate) fn ln_gamma(&self, precision: Precision) -> Result<Sci, Error> {
+    check_pole(self)?;
+    if self.compare::<true>(&Sci::ONE).is_eq()
+      || self.compare::<true>(&Scientific::from(2_u8).inner).is_eq()
+    {
+      // the only zeroes, which could not be found by increasing the precision
+      return Ok(Sci::ZERO);
+    }
+
+    // for a number of digits the required decimals depend on the magnitude of the result,
+    // increase them until the result is precise enough
+    let mut decimals = GUARD;
+    loop {
+      let (mut result, _) = ln_gamma(self, decimals);
+      let required = match precision {
+        Precision::Digits(digits) => digits - result.exponent0(),
+        Precision::Decimals(decimals) => decimals,
+      } + GUARD;
+      if required <= decimals {
+        result.round_assign(precision, RoundingMode::RPSP(RPSP));
+        return Ok(result);
+      }
+      decimals = required;
+    }
+  }
+}
+
+// gamma has poles at zero and the negative integers
+fn check_pole(value: &Sci) -> Result<(), Error> {
+  if value.is_integer() && (value.is_zero() || value.sign.is_negative()) {
+    Err(Error::DivisionByZero)
+  } else {
+    Ok(())
+  }
+}
+
+// ln(|gamma(x)|) with the given number of decimals and the sign of gamma(x)
+fn ln_gamma(x: &Sci, decimals: isize) -> (Sci, Sign) {
+  if x.compare::<true>(&Sci::POINT5).is_lt() {
+    // the reflection formula gamma(x) gamma(1 - x) = pi / sin(pi x), gamma(1 - x) is positive
+    let precision = decimals + GUARD;
+    let mut sine = sin_pi(x, precision);
+    let sign = sine.sign;
+    sine.sign = Sign::POSITIVE;
+    let (reflected, _) = ln_gamma(&Sci::ONE.sub(x), precision);
+    let mut result = ln(&pi(precision), precision)
+      .sub(&ln(&sine, precision))
+      .sub(&reflected);
+    result.truncate_assign(Precision::Decimals(decimals));
+    return (result, sign);
+  }
+
+  // move x to at least 1 with gamma(x) = gamma(x + 1) / x
+  let mut x = x.clone();
+  let mut divisor = Sci::ONE;
+  while x.compare::<true>(&Sci::ONE).is_lt() {
+    divisor = divisor.mul(&x);
+    x = x.add(&Sci::ONE);
+  }
+  let sign = divisor.sign;
+  divisor.sign = Sign::POSITIVE;
+
+  let mut result = spouge(&x.sub(&Sci::ONE), decimals);
+  if divisor.compare::<true>(&Sci::ONE).is_ne() {
+    result = result.sub(&ln(&divisor, decimals));
+  }
+  (result, sign)
+}
+
+// ln(gamma(z + 1)) for z >= 0 with Spouge's approximation:
+// gamma(z + 1) = (z + a)^(z + 1/2) e^-(z + a) (c_0 + sum(c_k / (z + k), k = 1..a)) with
+// c_0 = sqrt(2 pi) and c_k = (-1)^(k - 1) / (k - 1)! (a - k)^(k - 1/2) e^(a - k)
+fn spouge(z: &Sci, decimals: isize) -> Sci {
+  // the relative error is below (2 pi)^-a, which is less than 10^-(0.79 a)
+  let a = (decimals.max(1) as usize * 127 + 99) / 100 + 1;
+  // the terms are up to 10^(0.56 a) times larger than the sum, which is at least c_0
+  let digits = decimals + (a as isize * 56 + 99) / 100 + GUARD;
+
+  let precision = Precision::Digits(digits);
+  let mut sum = pi(digits)
+    .mul(&Scientific::from(2_u8).inner)
+    .sqrt(precision, false)
+    .expect("2 pi is positive");
+  // (a - k)^(k - 1/2) e^(a - k) = (a - k)^k e^(a - k) / sqrt(a - k), with e^(a - k) from
+  // the powers of e
+  let e = exp(&Sci::ONE, digits + GUARD).expect("e is not too large");
+  let mut powers_of_e = Vec::with_capacity(a);
+  powers_of_e.push(Sci::ONE);
+  for _ in 1..a {
+    let mut power = powers_of_e[powers_of_e.len() - 1].mul(&e);
+    power.truncate_assign(Precision::Digits(digits + GUARD));
+    powers_of_e.push(power);
+  }
+  let mut factorial = Sci::ONE;
+  for k in 1..a {
+    if k > 1 {
+      factorial = factorial.mul(&Scientific::from(k - 1).inner);
+    }
+    let a_minus_k = Scientific::from(a - k).inner;
+    let numerator = truncated(a_minus_k.powi(k), digits).mul(&powers_of_e[a - k]);
+    let sqrt = a_minus_k.sqrt(precision, false).expect("a - k is positive");
+    let divisor = truncated(sqrt.mul(&factorial), digits).mul(&z.add(&Scientific::from(k).inner));
+    let mut term = truncated(numerator, digits)
+      .div(&truncated(divisor, digits), precision, false)
+      .expect("the divisor is positive");
+    if k % 2 == 0 {
+      term.neg_assign();
+    }
+    sum = sum.add(&term);
+    sum.truncate_assign(Precision::Decimals(digits));
+  }
+
+  let z_plus_a = z.add(&Scientific::from(a).inner);
+  let z_plus_half = z.add(&Sci::POINT5);
+  let mut result = z_plus_half
+    .mul(&ln(&z_plus_a, decimals + z_plus_half.exponent0().max(0)))
+    .sub(&z_plus_a)
+    .add(&ln(&sum, decimals));
+  result.truncate_assign(Precision::Decimals(decimals));
+  result
+}
+
+fn truncated(mut value: Sci, digits: isize) -> Sci {
+  value.truncate_assign(Precision::Digits(digits));
+  value
+}
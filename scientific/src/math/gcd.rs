@@ -0,0 +1,135 @@
+use crate::conversion::limbs::trim;
+use crate::types::error::Error;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+impl Sci {
+  // greatest common divisor of the absolute values, zero if both are zero
+  pub(crate) fn gcd(&self, rhs: &Sci) -> Result<Sci, Error> {
+    if !self.is_integer() || !rhs.is_integer() {
+      return Err(Error::NumberIsNotAnInteger);
+    }
+    if self.is_zero() || rhs.is_zero() {
+      let mut result = if self.is_zero() { rhs } else { self }.clone();
+      result.sign = Sign::POSITIVE;
+      return Ok(result);
+    }
+    // gcd(a*10^k, b*10^k) = gcd(a, b)*10^k, so the trailing zeros are not expanded
+    let exponent = self.exponent.min(rhs.exponent);
+    let (mut a, mut b) = (self.clone(), rhs.clone());
+    a.shr_assign(exponent);
+    b.shr_assign(exponent);
+    // now one of them has no trailing zeros, the factors 2 and 5 of the other can be dropped
+    // beyond the count in the first one (which is smaller than 4 for each digit)
+    a.exponent = a.exponent.min(4 * b.len);
+    b.exponent = b.exponent.min(4 * a.len);
+    let gcd = binary_gcd(
+      a.to_le_limbs().map_err(|_| Error::NumberTooLarge)?,
+      b.to_le_limbs().map_err(|_| Error::NumberTooLarge)?,
+    );
+    Ok(Sci::from_le_limbs(false, &gcd, exponent))
+  }
+
+  // least common multiple of the absolute values, zero if one of them is zero
+  pub(crate) fn lcm(&self, rhs: &Sci) -> Result<Sci, Error> {
+    let mut gcd = self.gcd(rhs)?;
+    if self.is_zero() || rhs.is_zero() {
+      return Ok(Sci::ZERO);
+    }
+    // divide the value with fewer trailing zeros by the gcd, the power of ten cancels out
+    let (small, large) = if self.exponent <= rhs.exponent {
+      (self, rhs)
+    } else {
+      (rhs, self)
+    };
+    let mut small = small.clone();
+    gcd.shr_assign(small.exponent);
+    small.shr_assign(small.exponent);
+    let (quot, _) = small.div_rem(&gcd).expect("gcd is not zero");
+    let mut result = quot.mul(large);
+    result.sign = Sign::POSITIVE;
+    Ok(result)
+  }
+}
+
+// Stein's algorithm on little endian limbs, which only needs subtractions and shifts.
+fn binary_gcd(mut a: Vec<u64>, mut b: Vec<u64>) -> Vec<u64> {
+  if trim(&mut a) == 0 {
+    return b;
+  }
+  if trim(&mut b) == 0 {
+    return a;
+  }
+  let a_zeros = trailing_zeros(&a);
+  let b_zeros = trailing_zeros(&b);
+  shr_bits(&mut a, a_zeros);
+  shr_bits(&mut b, b_zeros);
+  // both are odd, the difference is even
+  loop {
+    if compare(&a, &b) == Ordering::Greater {
+      core::mem::swap(&mut a, &mut b);
+    }
+    sub_assign(&mut b, &a);
+    if trim(&mut b) == 0 {
+      break;
+    }
+    let zeros = trailing_zeros(&b);
+    shr_bits(&mut b, zeros);
+  }
+  shl_bits(&mut a, a_zeros.min(b_zeros));
+  a
+}
+
+// the number of trailing zero bits (the value must not be zero)
+fn trailing_zeros(value: &[u64]) -> usize {
+  let limbs = value.iter().take_while(|limb| **limb == 0).count();
+  limbs * 64 + value[limbs].trailing_zeros() as usize
+}
+
+fn shr_bits(value: &mut Vec<u64>, bits: usize) {
+  value.drain(..bits / 64);
+  let bits = bits % 64;
+  if bits > 0 {
+    for i in 0..value.len() {
+      let high = value.get(i + 1).map_or(0, |limb| limb << (64 - bits));
+      value[i] = (value[i] >> bits) | high;
+    }
+  }
+  trim(value);
+}
+
+fn shl_bits(value: &mut Vec<u64>, bits: usize) {
+  let bits_in_limb = bits % 64;
+  if bits_in_limb > 0 {
+    let mut carry = 0;
+    for limb in value.iter_mut() {
+      let next = *limb >> (64 - bits_in_limb);
+      *limb = (*limb << bits_in_limb) | carry;
+      carry = next;
+    }
+    if carry > 0 {
+      value.push(carry);
+    }
+  }
+  value.splice(..0, core::iter::repeat(0).take(bits / 64));
+}
+
+// compare two values without leading zero limbs
+fn compare(a: &[u64], b: &[u64]) -> Ordering {
+  a.len()
+    .cmp(&b.len())
+    .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+// value -= a, the value must not be smaller than a
+fn sub_assign(value: &mut [u64], a: &[u64]) {
+  let mut borrow = false;
+  for (i, limb) in value.iter_mut().enumerate() {
+    let (v, b1) = limb.overflowing_sub(a.get(i).copied().unwrap_or(0));
+    let (v, b2) = v.overflowing_sub(u64::from(borrow));
+    *limb = v;
+    borrow = b1 || b2;
+  }
+}
@@ -0,0 +1,166 @@
+use crate::types::error::Error;
+use crate::types::precision::Precision;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+use crate::types::sign::Sign;
+use alloc::vec;
+
+impl Sci {
+  // the n-th root truncated towards zero
+  pub(crate) fn iroot(&self, n: usize) -> Result<Sci, Error> {
+    if n == 0 {
+      return Err(Error::DivisionByZero);
+    }
+    if !self.is_integer() {
+      return Err(Error::NumberIsNotAnInteger);
+    }
+    if self.sign.is_negative() && !self.is_zero() {
+      if n % 2 == 0 {
+        return Err(Error::NumberIsNegative);
+      }
+      let mut value = self.clone();
+      value.sign = Sign::POSITIVE;
+      let mut result = iroot_abs(&value, n);
+      result.neg_assign();
+      return Ok(result);
+    }
+    Ok(iroot_abs(self, n))
+  }
+
+  // the square root rounded down and the remainder
+  pub(crate) fn isqrt(&self) -> Result<(Sci, Sci), Error> {
+    let root = self.iroot(2)?;
+    let remainder = self.sub(&root.mul(&root));
+    Ok((root, remainder))
+  }
+
+  pub(crate) fn is_perfect_square(&self) -> bool {
+    self
+      .isqrt()
+      .map_or(false, |(_, remainder)| remainder.is_zero())
+  }
+
+  // the smallest base (in absolute value) with the largest exponent
+  pub(crate) fn is_perfect_power(&self) -> Option<(Sci, usize)> {
+    if !self.is_integer() {
+      return None;
+    }
+    if self.compare::<false>(&Sci::ONE).is_le() {
+      // 0, 1 and -1 are powers with any exponent, use the smallest one
+      let exponent = if self.sign.is_negative() && !self.is_zero() {
+        3
+      } else {
+        2
+      };
+      return Some((self.clone(), exponent));
+    }
+
+    // only prime exponents have to be checked, the root itself may be a power again;
+    // the root is at least 2, so the exponent is at most log2(|self|) < 4 * digits
+    let max_exponent = usize::try_from(self.exponent0()).ok()?.saturating_mul(4);
+    let mut sieve = vec![true; max_exponent + 1];
+    for p in 2..=max_exponent {
+      if !sieve[p] {
+        continue;
+      }
+      for multiple in (p * p..=max_exponent).step_by(p) {
+        sieve[multiple] = false;
+      }
+      if p == 2 && self.sign.is_negative() {
+        continue;
+      }
+      let root = self.iroot(p).expect("self is an integer");
+      if root.compare::<false>(&Sci::ONE).is_le() {
+        // all roots with larger exponents are 1, too
+        break;
+      }
+      if root.powi(p).compare::<true>(self).is_eq() {
+        let (base, exponent) = root.is_perfect_power().unwrap_or((root, 1));
+        return Some((base, exponent * p));
+      }
+    }
+    None
+  }
+}
+
+// the n-th root of a non-negative value rounded down
+fn iroot_abs(value: &Sci, n: usize) -> Sci {
+  if value.is_zero() || n == 1 {
+    return value.clone();
+  }
+  let n_sci = Scientific::from(n).inner;
+  let n_minus_1 = Scientific::from(n - 1).inner;
+  // the root of the leading digits (with a shift which is a multiple of n) gives bounds,
+  // value < (leading + 1) * 10^shift, so lower <= root < upper
+  let n_isize = isize::try_from(n).unwrap_or(isize::MAX);
+  if n_isize / 4 >= value.exponent0() {
+    // value < 10^exponent0 < 2^n
+    return Sci::ONE;
+  }
+  let shift = match value.exponent0() - 38 {
+    excess if excess <= 0 => 0,
+    excess => (excess + n_isize - 1) / n_isize * n_isize,
+  };
+  let mut leading = value.clone();
+  leading.exponent -= shift;
+  leading.truncate_assign(Precision::INTEGER);
+  let leading = u128::try_from(&Scientific { inner: leading }).expect("at most 38 digits");
+  let leading_root = iroot_u128(leading, n);
+  if shift == 0 {
+    return Scientific::from(leading_root).inner;
+  }
+  let mut lower = Scientific::from(leading_root).inner;
+  lower.exponent += shift / n_isize;
+  let mut upper = Scientific::from(leading_root + 1).inner;
+  upper.exponent += shift / n_isize;
+
+  // the integer Newton iteration x = ((n - 1) * x + value / x^(n - 1)) / n decreases monotonically
+  // from an initial value larger than the root until it reaches the root, but it only converges
+  // quickly if upper is closer than root / n to the root, so bisect the interval until then
+  let mut root = loop {
+    let gap = upper.sub(&lower);
+    if gap.compare::<false>(&Sci::ONE).is_le() {
+      return lower;
+    }
+    if gap.mul(&n_sci).compare::<true>(&lower).is_lt() {
+      break upper;
+    }
+    let mut middle = lower.add(&upper).mul(&Sci::POINT5);
+    middle.truncate_assign(Precision::INTEGER);
+    if middle.powi(n).compare::<true>(value).is_le() {
+      lower = middle;
+    } else {
+      upper = middle;
+    }
+  };
+  loop {
+    let (quot, _) = value.div_rem(&root.powi(n - 1)).expect("root is not zero");
+    let (next, _) = root
+      .mul(&n_minus_1)
+      .add(&quot)
+      .div_rem(&n_sci)
+      .expect("n is not zero");
+    if next.compare::<true>(&root).is_ge() {
+      return root;
+    }
+    root = next;
+  }
+}
+
+// the n-th root rounded down, determined bit by bit
+pub(crate) fn iroot_u128(value: u128, n: usize) -> u128 {
+  let Ok(n) = u32::try_from(n) else {
+    return value.min(1);
+  };
+  let mut root = 0_u128;
+  for bit in (0..=((128 - value.leading_zeros()) / n).min(127)).rev() {
+    let candidate = root | (1 << bit);
+    if candidate
+      .checked_pow(n)
+      .map_or(false, |power| power <= value)
+    {
+      root = candidate;
+    }
+  }
+  root
+}
@@ -1,11 +1,23 @@
 pub(crate) mod add;
+pub(crate) mod add_assign;
 pub(crate) mod compare;
 pub(crate) mod div;
+pub(crate) mod div_euclid;
+pub(crate) mod elementary;
+pub(crate) mod factorial;
+pub(crate) mod fraction;
+pub(crate) mod gamma;
+pub(crate) mod gcd;
+pub(crate) mod iroot;
+pub(crate) mod modular;
 pub(crate) mod mul;
+pub(crate) mod mul_add;
 pub(crate) mod neg;
 pub(crate) mod powi;
+pub(crate) mod prime;
 pub(crate) mod round;
 pub(crate) mod shift;
 pub(crate) mod sqrt;
 pub(crate) mod sub;
+pub(crate) mod sum;
 pub(crate) mod truncate;
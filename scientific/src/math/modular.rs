@@ -0,0 +1,97 @@
+use crate::conversion::radix::MAX_DIGITS;
+use crate::types::error::Error;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+
+impl Sci {
+  // self^exponent mod modulus, the result has the sign of the modulus
+  pub(crate) fn mod_pow(&self, exponent: &Sci, modulus: &Sci) -> Result<Sci, Error> {
+    if !exponent.is_integer() {
+      return Err(Error::NumberIsNotAnInteger);
+    }
+    let modulus_abs = check_modulus(self, modulus)?;
+    if exponent.sign.is_negative() && !exponent.is_zero() {
+      return Err(Error::NumberIsNegative);
+    }
+    let exponent = exponent.to_le_limbs().map_err(|_| Error::NumberTooLarge)?;
+
+    // square and multiply, reducing after each step
+    let mut base = rem_euclid(self, &modulus_abs);
+    let mut result = rem_euclid(&Sci::ONE, &modulus_abs);
+    for (i, limb) in exponent.iter().enumerate() {
+      let bits = if i + 1 == exponent.len() {
+        64 - limb.leading_zeros()
+      } else {
+        64
+      };
+      for bit in 0..bits {
+        if limb >> bit & 1 == 1 {
+          result = rem_euclid(&result.mul(&base), &modulus_abs);
+        }
+        base = rem_euclid(&base.mul(&base), &modulus_abs);
+      }
+    }
+    Ok(with_sign_of(result, modulus))
+  }
+
+  // x with self * x = 1 mod modulus (None if it doesn't exist), x has the sign of the modulus
+  pub(crate) fn mod_inverse(&self, modulus: &Sci) -> Result<Option<Sci>, Error> {
+    let modulus_abs = check_modulus(self, modulus)?;
+
+    // extended Euclidean algorithm, only the coefficient of self is needed
+    let (mut old_r, mut r) = (rem_euclid(self, &modulus_abs), modulus_abs.clone());
+    let (mut old_s, mut s) = (Sci::ONE, Sci::ZERO);
+    while !r.is_zero() {
+      let (quot, rem) = old_r.div_rem(&r)?;
+      old_r = core::mem::replace(&mut r, rem);
+      let next_s = old_s.sub(&quot.mul(&s));
+      old_s = core::mem::replace(&mut s, next_s);
+    }
+    if old_r.compare::<true>(&Sci::ONE).is_ne() {
+      return Ok(None);
+    }
+    Ok(Some(with_sign_of(
+      rem_euclid(&old_s, &modulus_abs),
+      modulus,
+    )))
+  }
+}
+
+// check that value and modulus are integers with at most MAX_DIGITS digits and the modulus is
+// not zero, returns |modulus|
+fn check_modulus(value: &Sci, modulus: &Sci) -> Result<Sci, Error> {
+  if !value.is_integer() || !modulus.is_integer() {
+    return Err(Error::NumberIsNotAnInteger);
+  }
+  if modulus.is_zero() {
+    return Err(Error::DivisionByZero);
+  }
+  if [value, modulus]
+    .iter()
+    .any(|v| !v.is_zero() && v.exponent > MAX_DIGITS - v.len)
+  {
+    return Err(Error::NumberTooLarge);
+  }
+  let mut result = modulus.clone();
+  result.sign = Sign::POSITIVE;
+  Ok(result)
+}
+
+// the remainder in the range 0..modulus (the modulus is positive)
+fn rem_euclid(value: &Sci, modulus: &Sci) -> Sci {
+  let (_, rem) = value.div_rem(modulus).expect("modulus is not zero");
+  if rem.sign.is_negative() && !rem.is_zero() {
+    rem.add(modulus)
+  } else {
+    rem
+  }
+}
+
+// move the result from 0..|modulus| into modulus..0 for a negative modulus
+fn with_sign_of(result: Sci, modulus: &Sci) -> Sci {
+  if modulus.sign.is_negative() && !result.is_zero() {
+    result.add(modulus)
+  } else {
+    result
+  }
+}
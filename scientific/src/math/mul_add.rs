@@ -0,0 +1,81 @@
+use crate::types::precision::Precision;
+use crate::types::rounding_mode::RoundingMode;
+use crate::types::sci::Sci;
+use crate::types::sign::Sign;
+
+impl Sci {
+  // self * b + c with a single rounding, the product is not calculated if it is too small to
+  // matter and the digits of c far below the rounding position are not added
+  pub(crate) fn mul_add(
+    &self,
+    b: &Sci,
+    c: &Sci,
+    precision: Precision,
+    rounding: RoundingMode,
+  ) -> Sci {
+    let mut result = if self.is_zero() || b.is_zero() {
+      c.clone()
+    } else if c.is_zero() {
+      self.mul(b)
+    } else {
+      let sign = self.sign ^ b.sign;
+      // position below the rounding position (with some room as the result may be smaller than c)
+      let m = c
+        .exponent
+        .min(c.exponent0() - c.precision_len(precision) - 2);
+      // |self * b| < 10^(self.exponent0 + b.exponent0)
+      if self.exponent0() + b.exponent0() <= m {
+        if let Some(result) = round_sticky(c, sign, m, precision, rounding) {
+          return result;
+        }
+      }
+
+      let product = self.mul(b);
+      let larger = if product.exponent0() > c.exponent0() {
+        &product
+      } else {
+        c
+      };
+      let m = product
+        .exponent
+        .min(larger.exponent0() - larger.precision_len(precision) - 2);
+      if c.exponent < m {
+        // c = high + low with |low| < 10^m
+        let mut high = c.clone();
+        high.truncate_assign(Precision::Decimals(-m));
+        if let Some(result) = round_sticky(&product.add(&high), c.sign, m, precision, rounding) {
+          return result;
+        }
+      }
+      product.add(c)
+    };
+    result.round_assign(precision, rounding);
+    result
+  }
+}
+
+// round(high + low) where high is a multiple of 10^m, |low| < 10^m and low has the given sign
+// (but is not zero); low is replaced by ±10^(m - 1), which results in the same digits down to 10^m
+// and a nonzero remainder; returns None if this could change the result
+fn round_sticky(
+  high: &Sci,
+  sign: Sign,
+  m: isize,
+  precision: Precision,
+  rounding: RoundingMode,
+) -> Option<Sci> {
+  // the exact value is between high and other, both are multiples of 10^m
+  let other = high.add(&Sci::one(sign, m));
+  if high.is_zero() || other.is_zero() {
+    // the number of digits of the result is unknown
+    return None;
+  }
+  let smaller = if high.sign == sign { high } else { &other };
+  // the rounding position must be above m, for the smallest possible result
+  if smaller.exponent0() - smaller.precision_len(precision) <= m {
+    return None;
+  }
+  let mut result = high.add(&Sci::one(sign, m - 1));
+  result.round_assign(precision, rounding);
+  Some(result)
+}
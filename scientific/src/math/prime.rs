@@ -0,0 +1,347 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::precision::Precision;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+use crate::types::sign::Sign;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+// All primes below 256, used for trial division and as bases for Miller-Rabin.
+const SMALL_PRIMES: [u8; 54] = [
+  2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+  101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+  197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+// Values below this are prime if they have no factor in SMALL_PRIMES.
+const TRIAL_DIVISION_LIMIT: u64 = 256 * 256;
+// Miller-Rabin with the first 12 primes as bases is deterministic for all u64.
+const DETERMINISTIC_BASES_U64: usize = 12;
+// Miller-Rabin with the first 13 primes as bases is deterministic below this value.
+const DETERMINISTIC_LIMIT: u128 = 3_317_044_064_679_887_385_961_981;
+const DETERMINISTIC_BASES: usize = 13;
+// The rounds used by next_prime and factorize for larger values.
+pub(crate) const DEFAULT_ROUNDS: usize = 20;
+
+impl Sci {
+  pub(crate) fn is_probable_prime(&self, rounds: usize) -> bool {
+    if self.is_zero() || self.sign.is_negative() || !self.is_integer() {
+      return false;
+    }
+    let small = u64::try_from(&Scientific {
+      inner: self.clone(),
+    })
+    .ok();
+    if small.map_or(false, |n| n < 2) {
+      return false;
+    }
+    for p in SMALL_PRIMES {
+      if self.rem_u64(u64::from(p)) == 0 {
+        return small == Some(u64::from(p));
+      }
+    }
+    if let Some(n) = small {
+      return n < TRIAL_DIVISION_LIMIT || miller_rabin_u64(n);
+    }
+
+    let deterministic = u128::try_from(&Scientific {
+      inner: self.clone(),
+    })
+    .map_or(false, |n| n < DETERMINISTIC_LIMIT);
+    let rounds = if deterministic {
+      DETERMINISTIC_BASES
+    } else {
+      rounds
+    };
+    // the first rounds primes are used as bases, then the integers starting at 256
+    let bases = SMALL_PRIMES
+      .iter()
+      .map(|p| u64::from(*p))
+      .chain(256..)
+      .take(rounds);
+    self.miller_rabin(bases)
+  }
+
+  // n is odd and larger than all bases
+  fn miller_rabin<I: Iterator<Item = u64>>(&self, bases: I) -> bool {
+    // n - 1 = d * 2^s with an odd d
+    let n_minus_1 = self.sub(&Sci::ONE);
+    let two = Scientific::from(2_u8).inner;
+    let mut d = n_minus_1.clone();
+    let mut s = 0;
+    loop {
+      let (quot, rem) = d.div_rem(&two).expect("two is not zero");
+      if !rem.is_zero() {
+        break;
+      }
+      d = quot;
+      s += 1;
+    }
+
+    'bases: for base in bases {
+      let mut x = Scientific::from(base)
+        .inner
+        .mod_pow(&d, self)
+        .expect("all values are positive integers");
+      if x.compare::<true>(&Sci::ONE).is_eq() || x.compare::<true>(&n_minus_1).is_eq() {
+        continue;
+      }
+      for _ in 1..s {
+        x = x.mul(&x).div_rem(self).expect("n is not zero").1;
+        if x.compare::<true>(&n_minus_1).is_eq() {
+          continue 'bases;
+        }
+      }
+      return false;
+    }
+    true
+  }
+
+  pub(crate) fn next_prime(&self) -> Sci {
+    let two = Scientific::from(2_u8).inner;
+    if self.compare::<true>(&two).is_lt() {
+      return two;
+    }
+    let mut candidate = self.clone();
+    candidate.truncate_assign(Precision::INTEGER);
+    candidate = candidate.add(&Sci::ONE);
+    if candidate.rem_u64(2) == 0 {
+      candidate = candidate.add(&Sci::ONE);
+    }
+    while !candidate.is_probable_prime(DEFAULT_ROUNDS) {
+      candidate = candidate.add(&two);
+    }
+    candidate
+  }
+
+  // the prime factors of the absolute value with their multiplicity, sorted
+  pub(crate) fn factorize(&self) -> Result<Vec<(Sci, usize)>, ConversionError> {
+    if self.is_zero() {
+      return Ok(Vec::new());
+    }
+    if !self.is_integer() {
+      return Err(ConversionError::NumberIsNotAnInteger);
+    }
+    let mut n = self.clone();
+    n.sign = Sign::POSITIVE;
+    let mut result: Vec<(Sci, usize)> = Vec::new();
+
+    // trial division
+    for p in SMALL_PRIMES {
+      let mut count = 0;
+      while n.rem_u64(u64::from(p)) == 0 {
+        n = n
+          .div_rem(&Scientific::from(p).inner)
+          .expect("p is not zero")
+          .0;
+        count += 1;
+      }
+      if count > 0 {
+        result.push((Scientific::from(p).inner, count));
+      }
+    }
+
+    // Pollard's rho for the remaining factors
+    let mut factors = Vec::new();
+    let mut remaining = Vec::new();
+    if n.compare::<true>(&Sci::ONE).is_gt() {
+      remaining.push(n);
+    }
+    while let Some(n) = remaining.pop() {
+      if let Ok(small) = u64::try_from(&Scientific { inner: n.clone() }) {
+        factorize_u64(small, &mut factors);
+      } else if n.is_probable_prime(DEFAULT_ROUNDS) {
+        factors.push(n);
+      } else {
+        let factor = pollard_rho(&n);
+        let (cofactor, _) = n.div_rem(&factor).expect("factor is not zero");
+        remaining.push(factor);
+        remaining.push(cofactor);
+      }
+    }
+    factors.sort_by(Sci::compare::<true>);
+    for factor in factors {
+      match result.last_mut() {
+        Some((last, count)) if last.compare::<true>(&factor) == Ordering::Equal => *count += 1,
+        _ => result.push((factor, 1)),
+      }
+    }
+    Ok(result)
+  }
+
+  // the remainder of the division of a non-negative integer by a small divisor
+  fn rem_u64(&self, divisor: u64) -> u64 {
+    let divisor_u128 = u128::from(divisor);
+    let mut rem = 0;
+    for i in 0..self.len {
+      rem = (rem * 10 + self.data[i] as u128) % divisor_u128;
+    }
+    // the exponent may be huge, multiply with 10^exponent modulo divisor
+    let exponent = u64::try_from(self.exponent).unwrap_or(0);
+    mul_mod(
+      rem as u64,
+      pow_mod(10 % divisor, exponent, divisor),
+      divisor,
+    )
+  }
+}
+
+// A non-trivial factor of a composite n without small factors (Brent's variant of Pollard's rho).
+fn pollard_rho(n: &Sci) -> Sci {
+  // the differences are multiplied and checked together, which needs only one gcd per batch
+  const BATCH: usize = 64;
+  let step =
+    |x: &Sci, increment: &Sci| x.mul(x).add(increment).div_rem(n).expect("n is not zero").1;
+  let distance = |x: &Sci, y: &Sci| {
+    let mut result = x.sub(y);
+    result.sign = Sign::POSITIVE;
+    result
+  };
+  let gcd = |x: &Sci| x.gcd(n).expect("all values are integers");
+
+  let mut increment = Sci::ONE;
+  loop {
+    let mut y = Scientific::from(2_u8).inner;
+    let mut x;
+    let mut ys;
+    let mut product = Sci::ONE;
+    let mut divisor;
+    let mut length = 1;
+    loop {
+      x = y.clone();
+      for _ in 0..length {
+        y = step(&y, &increment);
+      }
+      let mut done = 0;
+      loop {
+        ys = y.clone();
+        for _ in 0..BATCH.min(length - done) {
+          y = step(&y, &increment);
+          product = product
+            .mul(&distance(&x, &y))
+            .div_rem(n)
+            .expect("n is not zero")
+            .1;
+        }
+        divisor = gcd(&product);
+        done += BATCH;
+        if done >= length || divisor.compare::<true>(&Sci::ONE).is_ne() {
+          break;
+        }
+      }
+      length *= 2;
+      if divisor.compare::<true>(&Sci::ONE).is_ne() {
+        break;
+      }
+    }
+    if divisor.compare::<true>(n).is_eq() {
+      // the batch contained the factor and n itself, repeat the steps one by one
+      loop {
+        ys = step(&ys, &increment);
+        divisor = gcd(&distance(&x, &ys));
+        if divisor.compare::<true>(&Sci::ONE).is_ne() {
+          break;
+        }
+      }
+    }
+    if divisor.compare::<true>(n).is_ne() {
+      return divisor;
+    }
+    // failed, try another polynomial
+    increment = increment.add(&Sci::ONE);
+  }
+}
+
+// The same algorithms for values which fit into an u64, which is much faster.
+
+fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+  (u128::from(a) * u128::from(b) % u128::from(n)) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, n: u64) -> u64 {
+  let mut result = 1;
+  while exponent > 0 {
+    if exponent & 1 == 1 {
+      result = mul_mod(result, base, n);
+    }
+    base = mul_mod(base, base, n);
+    exponent >>= 1;
+  }
+  result
+}
+
+// n is odd and has no factor in SMALL_PRIMES
+fn miller_rabin_u64(n: u64) -> bool {
+  let s = (n - 1).trailing_zeros();
+  let d = (n - 1) >> s;
+  'bases: for base in &SMALL_PRIMES[..DETERMINISTIC_BASES_U64] {
+    let mut x = pow_mod(u64::from(*base), d, n);
+    if x == 1 || x == n - 1 {
+      continue;
+    }
+    for _ in 1..s {
+      x = mul_mod(x, x, n);
+      if x == n - 1 {
+        continue 'bases;
+      }
+    }
+    return false;
+  }
+  true
+}
+
+// n has no factor in SMALL_PRIMES
+fn factorize_u64(n: u64, factors: &mut Vec<Sci>) {
+  if n < TRIAL_DIVISION_LIMIT || miller_rabin_u64(n) {
+    factors.push(Scientific::from(n).inner);
+  } else {
+    let factor = pollard_rho_u64(n);
+    factorize_u64(factor, factors);
+    factorize_u64(n / factor, factors);
+  }
+}
+
+fn pollard_rho_u64(n: u64) -> u64 {
+  const BATCH: u64 = 64;
+  let gcd = |mut a: u64, mut b: u64| {
+    while b != 0 {
+      (a, b) = (b, a % b);
+    }
+    a
+  };
+  for increment in 1_u64.. {
+    let step =
+      |x: u64| ((u128::from(x) * u128::from(x) + u128::from(increment)) % u128::from(n)) as u64;
+    let (mut x, mut y, mut ys) = (0, 2, 2);
+    let (mut product, mut divisor, mut length) = (1, 1, 1);
+    while divisor == 1 {
+      x = y;
+      for _ in 0..length {
+        y = step(y);
+      }
+      let mut done = 0;
+      while done < length && divisor == 1 {
+        ys = y;
+        for _ in 0..BATCH.min(length - done) {
+          y = step(y);
+          product = mul_mod(product, x.abs_diff(y), n);
+        }
+        divisor = gcd(product, n);
+        done += BATCH;
+      }
+      length *= 2;
+    }
+    if divisor == n {
+      loop {
+        ys = step(ys);
+        divisor = gcd(x.abs_diff(ys), n);
+        if divisor != 1 {
+          break;
+        }
+      }
+    }
+    if divisor != n {
+      return divisor;
+    }
+  }
+  unreachable!()
+}
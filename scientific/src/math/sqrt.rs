@@ -1,8 +1,10 @@
+use crate::math::iroot::iroot_u128;
 use crate::types::error::Error;
 use crate::types::precision::Precision;
 use crate::types::rounding_mode::RoundingMode;
 use crate::types::rounding_rpsp::RPSP;
 use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
 use crate::types::sign::Sign;
 use core::cmp::Ordering;
 
@@ -30,24 +32,29 @@ impl Sci {
 
 // Babylonian method
 fn nz_sqrt(value: &Sci, precision: Precision, use_rpsp: bool) -> Result<Sci, Error> {
-  let mut guess = value.clone();
-  guess.shr_assign(value.exponent1() / 2 - 1);
-  limit(&mut guess, precision);
+  let mut guess = initial_guess(value);
 
-  #[cfg(all(feature = "debug", feature = "std"))]
-  {
-    let f_guess = guess.to_f64();
-    let f_value = value.to_f64();
-    let f_sqrt_value = f_value.sqrt();
-    if f_guess.is_finite() && f_value.is_finite() && f_sqrt_value.is_finite() {
-      assert!(
-        f_guess > f_sqrt_value,
-        "{}",
-        format!("initial guess {f_guess} should be larger than sqrt({f_value})={f_sqrt_value}")
-      );
-    }
+  #[cfg(feature = "debug")]
+  assert!(
+    value.compare::<false>(&guess.mul(&guess)) != Ordering::Greater,
+    "initial guess should be larger than the root"
+  );
+
+  // Newton's method doubles the number of correct digits in every step, only the last steps
+  // need the full precision
+  let mut correct = 18;
+  while 2 * correct < guess.precision_len(precision) {
+    correct *= 2;
+    let step_precision = Precision::Digits(correct + 2);
+    guess = Sci::POINT5.mul(&guess.add(&value.div(&guess, step_precision, false)?));
+    limit(&mut guess, step_precision);
+    // the step is above the root, add more than the truncation to stay above it
+    guess = guess.add(&Sci::one(Sign::POSITIVE, guess.exponent0() - correct - 1));
   }
 
+  // if the limited guess is below the root it is already the truncated root
+  limit(&mut guess, precision);
+
   loop {
     let mut next_guess = Sci::POINT5.mul(
       &(guess.add(&value.div(
@@ -89,7 +96,7 @@ fn nz_sqrt(value: &Sci, precision: Precision, use_rpsp: bool) -> Result<Sci, Err
   }
 
   // fix limit (there may be trailing zeroes due to how limit works)
-  while guess.data[guess.len - 1] == 0 {
+  while guess.len > 0 && guess.data[guess.len - 1] == 0 {
     guess.len -= 1;
     guess.exponent += 1;
   }
@@ -97,6 +104,23 @@ fn nz_sqrt(value: &Sci, precision: Precision, use_rpsp: bool) -> Result<Sci, Err
   Ok(guess)
 }
 
+// a guess larger than the root with about 18 correct digits, from the integer square root of
+// the leading digits (with an even shift)
+fn initial_guess(value: &Sci) -> Sci {
+  let mut shift = value.exponent1() - 36;
+  if shift % 2 != 0 {
+    shift -= 1;
+  }
+  let mut leading = value.clone();
+  leading.exponent -= shift;
+  leading.truncate_assign(Precision::INTEGER);
+  let leading = u128::try_from(&Scientific { inner: leading }).expect("at most 38 digits");
+  // leading <= value / 10^shift < leading + 1 <= (isqrt(leading) + 1)^2
+  let mut guess = Scientific::from(iroot_u128(leading, 2) + 1).inner;
+  guess.exponent += shift / 2;
+  guess
+}
+
 #[inline]
 fn limit(value: &mut Sci, precision: Precision) {
   let len = value.precision_len(precision).max(1);
@@ -0,0 +1,135 @@
+use crate::types::accumulator::Accumulator;
+use crate::types::precision::Precision;
+use crate::types::rounding::Rounding;
+use crate::types::rounding_mode::RoundingMode;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+use crate::types::sign::Sign;
+use alloc::vec::Vec;
+
+// Additional digits below the rounding position which are added exactly.
+const GUARD: isize = 2;
+
+impl Sci {
+  // the sum of the values with a single rounding; only the digits down to a few digits below the
+  // rounding position are added exactly, the digits below only matter if the sum is close to a
+  // point where the rounding changes (and often only their sign is needed)
+  pub(crate) fn sum_rounded(values: Vec<Sci>, precision: Precision, rounding: RoundingMode) -> Sci {
+    // sum = high + sum(rest), high is exact and rest contains the digits which are not yet added
+    let mut high = Sci::ZERO;
+    let mut rest = values;
+    rest.retain(|value| !value.is_zero());
+    let mut step = GUARD;
+    loop {
+      let Some(top) = rest.iter().map(Sci::exponent0).max() else {
+        high.round_assign(precision, rounding);
+        return high;
+      };
+      // |sum(rest)| < rest.len() * 10^top <= 10^bound
+      let bound = top + digits(rest.len());
+      if let Some(result) = round_interval(&high, bound, &mut rest, precision, rounding) {
+        return result;
+      }
+
+      // add the digits of rest down to a few digits below the (estimated) rounding position,
+      // or more if this was not enough
+      let magnitude = if high.is_zero() {
+        bound
+      } else {
+        high.exponent0().max(bound)
+      };
+      let position = match precision {
+        Precision::Digits(digits) => magnitude - digits,
+        Precision::Decimals(decimals) => -decimals,
+      };
+      let bottom = (position - 2 - GUARD).min(top - step) - digits(rest.len());
+      step *= 2;
+
+      let mut acc = Accumulator::from(Scientific { inner: high });
+      let mut next = Vec::new();
+      for value in rest {
+        if value.exponent >= bottom {
+          acc.add(&Scientific { inner: value });
+        } else if value.exponent0() <= bottom {
+          next.push(value);
+        } else {
+          let mut upper = value.clone();
+          upper.truncate_assign(Precision::Decimals(-bottom));
+          let lower = value.sub(&upper);
+          if !lower.is_zero() {
+            next.push(lower);
+          }
+          acc.add(&Scientific { inner: upper });
+        }
+      }
+      high = acc.into_sum().inner;
+      rest = next;
+    }
+  }
+}
+
+// round(high + sum(rest)) with |sum(rest)| < 10^bound, if the result does not depend on the
+// exact value of sum(rest)
+fn round_interval(
+  high: &Sci,
+  bound: isize,
+  rest: &mut Vec<Sci>,
+  precision: Precision,
+  rounding: RoundingMode,
+) -> Option<Sci> {
+  // all points where the rounding may change (including the ties and zero for decimals) are
+  // multiples of 10^(position - 1), they are at least 10^(bound + 1) apart
+  let position = if high.is_zero() || high.exponent1() <= bound {
+    // the interval may contain zero
+    match precision {
+      Precision::Digits(_) => return None,
+      Precision::Decimals(decimals) if high.is_zero() => -decimals,
+      Precision::Decimals(_) => return None,
+    }
+  } else {
+    // the values in the interval are at least 10^(high.exponent1() - 1)
+    let smaller = Sci::one(Sign::POSITIVE, high.exponent1() - 1);
+    smaller.exponent0() - smaller.precision_len(precision)
+  };
+  if position - 2 < bound {
+    return None;
+  }
+
+  // the distance of high to the points below and above
+  let mut upper = high.clone();
+  upper.truncate_assign(Precision::Decimals(1 - position));
+  let below = high.sub(&upper);
+  if below.is_zero() {
+    // high is such a point, the result depends only on the sign of sum(rest)
+    let sign = Sci::sum_rounded(
+      core::mem::take(rest),
+      Precision::Digits(1),
+      RoundingMode::Rounding(Rounding::RoundTowardsZero),
+    );
+    let mut result = if sign.is_zero() {
+      high.clone()
+    } else {
+      high.add(&Sci::one(sign.sign, position - 2))
+    };
+    result.round_assign(precision, rounding);
+    return Some(result);
+  }
+  let mut above = Sci::one(below.sign, position - 1).sub(&below);
+  above.sign = Sign::POSITIVE;
+  if below.exponent0() <= bound || above.exponent0() <= bound {
+    return None;
+  }
+  let mut result = high.clone();
+  result.round_assign(precision, rounding);
+  Some(result)
+}
+
+// the number of decimal digits of n
+fn digits(mut n: usize) -> isize {
+  let mut digits = 1;
+  while n >= 10 {
+    n /= 10;
+    digits += 1;
+  }
+  digits
+}
@@ -0,0 +1,103 @@
+use crate::types::owner::Owner;
+use crate::types::ptr::Ptr;
+use crate::types::sci::Sci;
+use crate::types::scientific::Scientific;
+
+// minimal number of spare digits kept in front of and after the sum when the data is grown
+const SPARE: isize = 16;
+
+/// Accumulator for the fast summation of many values.
+///
+/// It keeps spare room in front of and after the digits of the sum,
+/// so adding a value usually neither allocates nor moves any digits.
+/// The result is exact, exactly like repeatedly using `+=`.
+///
+/// ```
+/// # use scientific::{Accumulator, Scientific};
+/// # use std::str::FromStr;
+/// let mut acc = Accumulator::new();
+/// acc.add(&Scientific::from_str("1.5").unwrap());
+/// acc.add(&Scientific::from_str("200").unwrap());
+/// acc.sub(&Scientific::from_str("0.25").unwrap());
+/// assert_eq!(acc.into_sum(), Scientific::from_str("201.25").unwrap());
+/// ```
+#[derive(Clone)]
+#[must_use]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub struct Accumulator {
+  sum: Sci,
+}
+
+#[cfg_attr(no_re_export, allow(dead_code))]
+impl Accumulator {
+  /// Create an accumulator with a sum of zero.
+  #[inline]
+  pub const fn new() -> Accumulator {
+    Accumulator { sum: Sci::ZERO }
+  }
+
+  /// Add a value to the sum.
+  #[inline]
+  pub fn add(&mut self, value: &Scientific) {
+    let spare = SPARE.max(self.sum.len / 2);
+    self.sum.add_assign(&value.inner, spare);
+  }
+
+  /// Subtract a value from the sum.
+  #[inline]
+  pub fn sub(&mut self, value: &Scientific) {
+    let mut value = value.inner.clone();
+    value.neg_assign();
+    let spare = SPARE.max(self.sum.len / 2);
+    self.sum.add_assign(&value, spare);
+  }
+
+  /// Get the current sum.
+  ///
+  /// The digits are copied, so the accumulator keeps its buffer for further additions.
+  #[inline]
+  pub fn sum(&self) -> Scientific {
+    if self.sum.is_zero() {
+      return Scientific::ZERO;
+    }
+    let mut vec = self.sum.data.as_slice(self.sum.len).to_vec();
+    Scientific {
+      inner: Sci {
+        sign: self.sum.sign,
+        data: Ptr::new_mut(vec.as_mut_slice()),
+        len: self.sum.len,
+        exponent: self.sum.exponent,
+        owner: Owner::new(vec),
+      },
+    }
+  }
+
+  /// Consume the accumulator and return the sum.
+  #[inline]
+  pub fn into_sum(self) -> Scientific {
+    Scientific { inner: self.sum }
+  }
+}
+
+impl Default for Accumulator {
+  #[inline]
+  fn default() -> Self {
+    Accumulator::new()
+  }
+}
+
+impl From<Scientific> for Accumulator {
+  #[inline]
+  fn from(value: Scientific) -> Self {
+    Accumulator { sum: value.inner }
+  }
+}
+
+impl<'a> Extend<&'a Scientific> for Accumulator {
+  #[inline]
+  fn extend<T: IntoIterator<Item = &'a Scientific>>(&mut self, iter: T) {
+    for value in iter {
+      self.add(value);
+    }
+  }
+}
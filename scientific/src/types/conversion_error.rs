@@ -2,8 +2,9 @@ use core::fmt::{Display, Formatter};
 
 /// Errors which can occur during conversion.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum ConversionError {
-  /// The float is not finite. (Only for `From<float>` conversion.)
+  /// The float is not finite. (Only for `From<float>` conversion, Postgres `NUMERIC` and BSON `Decimal128`.)
   FloatIsNotFinite,
   /// Number is too large. (Only for `Into<integer>` conversion.)
   NumberTooLarge,
@@ -13,8 +14,10 @@ pub enum ConversionError {
   NumberIsNegative,
   /// Number is not an integer. (Only for `Into<integer>` conversion.)
   NumberIsNotAnInteger,
-  /// Exponent is too large for this platform. (Only used by [`Scientific::from_bytes`](crate::Scientific::from_bytes).)
+  /// Exponent is too large for this platform. (Only used by [`Scientific::from_bytes`](crate::Scientific::from_bytes), [`Scientific::from_sortable_bytes`](crate::Scientific::from_sortable_bytes) and [`Scientific::from_cbor_decimal`](crate::Scientific::from_cbor_decimal).)
   ExponentTooLargeForThisPlatform,
+  /// The precision is larger than the maximum of the target type. (Only used by [`Scientific::to_decimal128`](crate::Scientific::to_decimal128) and [`Scientific::to_decimal256`](crate::Scientific::to_decimal256).)
+  PrecisionTooLarge,
 }
 
 impl Display for ConversionError {
@@ -28,6 +31,7 @@ impl Display for ConversionError {
       ConversionError::ExponentTooLargeForThisPlatform => {
         f.write_str("Exponent is too large for this platform")
       }
+      ConversionError::PrecisionTooLarge => f.write_str("Precision too large"),
     }
   }
 }
@@ -35,3 +39,11 @@ impl Display for ConversionError {
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for ConversionError {}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<ConversionError> for std::io::Error {
+  fn from(value: ConversionError) -> Self {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, value)
+  }
+}
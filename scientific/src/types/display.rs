@@ -38,6 +38,43 @@ impl Sci {
     Ok(())
   }
 
+  // The value must be rounded/truncated to at least the requested decimals.
+  #[cfg(feature = "serde")]
+  pub(crate) fn display_fixed<W: Write>(
+    &self,
+    f: &mut W,
+    decimals: isize,
+  ) -> Result<(), core::fmt::Error> {
+    let exp = if self.is_zero() {
+      0
+    } else {
+      if self.sign.is_negative() {
+        f.write_char('-')?;
+      }
+      self.exponent0()
+    };
+    if exp <= 0 {
+      f.write_char('0')?;
+    } else {
+      let mid = exp.min(self.len);
+      self.data.write_chars(f, 0..mid)?;
+      for _ in mid..exp {
+        f.write_char('0')?;
+      }
+    }
+    if decimals > 0 {
+      f.write_char('.')?;
+      for i in exp..exp + decimals {
+        if i >= 0 && i < self.len {
+          self.data.write_char(f, i)?;
+        } else {
+          f.write_char('0')?;
+        }
+      }
+    }
+    Ok(())
+  }
+
   pub(crate) fn display(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
     if self.is_zero() {
       f.write_char('0')
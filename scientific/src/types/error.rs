@@ -2,11 +2,17 @@ use core::fmt::{Display, Formatter};
 
 /// Errors which can occur during calculation.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
-  /// Used by `div`.
+  /// Used by `div`, `mod_pow` and `mod_inverse`.
   DivisionByZero,
-  /// Used by `sqrt`.
+  /// Used by `sqrt` and `mod_pow`.
   NumberIsNegative,
+  /// Used by `gcd`, `lcm`, `mod_pow`, `mod_inverse`, `isqrt` and `iroot`.
+  NumberIsNotAnInteger,
+  /// The exponent of the result doesn't fit into an `isize`, also for results too close to zero,
+  /// or an integer has too many digits. Used by `gamma_rpsp`, `gcd`, `lcm`, `mod_pow` and `mod_inverse`.
+  NumberTooLarge,
 }
 
 impl Display for Error {
@@ -14,6 +20,8 @@ impl Display for Error {
     match self {
       Error::DivisionByZero => f.write_str("Division by zero"),
       Error::NumberIsNegative => f.write_str("Number is negative"),
+      Error::NumberIsNotAnInteger => f.write_str("Number is not an integer"),
+      Error::NumberTooLarge => f.write_str("Number too large"),
     }
   }
 }
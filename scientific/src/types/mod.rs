@@ -1,10 +1,14 @@
+pub(crate) mod accumulator;
 pub(crate) mod builder;
 pub(crate) mod conversion_error;
 pub(crate) mod debug;
 pub(crate) mod display;
 pub(crate) mod error;
 pub(crate) mod hash;
+#[cfg(feature = "num-traits")]
+pub(crate) mod num_traits_impl;
 pub(crate) mod owner;
+pub(crate) mod polynomial;
 pub(crate) mod precision;
 pub(crate) mod ptr;
 pub(crate) mod rounding;
@@ -13,7 +17,28 @@ pub(crate) mod rounding_rpsp;
 pub(crate) mod sci;
 pub(crate) mod scientific;
 #[cfg(feature = "serde")]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub mod serde_as_bytes;
+#[cfg(feature = "serde")]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub mod serde_as_f64;
+#[cfg(feature = "serde")]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub mod serde_as_fixed;
+#[cfg(feature = "serde")]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub mod serde_as_number;
+#[cfg(feature = "serde")]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub mod serde_as_string;
+#[cfg(feature = "serde")]
 pub(crate) mod serde_de;
 #[cfg(feature = "serde")]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub mod serde_exact_float;
+#[cfg(feature = "serde")]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub(crate) mod serde_option;
+#[cfg(feature = "serde")]
 pub(crate) mod serde_ser;
 pub(crate) mod sign;
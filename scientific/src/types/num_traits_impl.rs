@@ -0,0 +1,269 @@
+use crate::types::conversion_error::ConversionError;
+use crate::types::precision::Precision;
+use crate::types::scientific::Scientific;
+use core::convert::TryFrom;
+use num_traits::{
+  CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, ConstOne,
+  ConstZero, Euclid, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero,
+};
+
+impl Zero for Scientific {
+  #[inline]
+  fn zero() -> Self {
+    Scientific::ZERO
+  }
+
+  #[inline]
+  fn is_zero(&self) -> bool {
+    Scientific::is_zero(self)
+  }
+}
+
+impl ConstZero for Scientific {
+  const ZERO: Self = Scientific::ZERO;
+}
+
+impl One for Scientific {
+  #[inline]
+  fn one() -> Self {
+    Scientific::ONE
+  }
+
+  #[inline]
+  fn is_one(&self) -> bool {
+    self == &Scientific::ONE
+  }
+}
+
+impl ConstOne for Scientific {
+  const ONE: Self = Scientific::ONE;
+}
+
+impl Num for Scientific {
+  type FromStrRadixErr = ConversionError;
+
+  /// Radix 10 accepts all numbers (like [`from_str`](core::str::FromStr::from_str)), all other
+  /// radixes only integers (see [`Scientific::from_str_radix_integer`]).
+  fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+    if radix == 10 {
+      str.parse()
+    } else {
+      Scientific::from_str_radix_integer(str, radix)
+    }
+  }
+}
+
+impl Signed for Scientific {
+  #[inline]
+  fn abs(&self) -> Self {
+    Scientific::abs(self)
+  }
+
+  fn abs_sub(&self, other: &Self) -> Self {
+    if self <= other {
+      Scientific::ZERO
+    } else {
+      self - other
+    }
+  }
+
+  fn signum(&self) -> Self {
+    if self.is_sign_negative() {
+      -Scientific::ONE
+    } else if self.is_sign_positive() {
+      Scientific::ONE
+    } else {
+      Scientific::ZERO
+    }
+  }
+
+  #[inline]
+  fn is_positive(&self) -> bool {
+    self.is_sign_positive()
+  }
+
+  #[inline]
+  fn is_negative(&self) -> bool {
+    self.is_sign_negative()
+  }
+}
+
+impl FromPrimitive for Scientific {
+  #[inline]
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(Scientific::from(n))
+  }
+
+  #[inline]
+  fn from_i128(n: i128) -> Option<Self> {
+    Some(Scientific::from(n))
+  }
+
+  #[inline]
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(Scientific::from(n))
+  }
+
+  #[inline]
+  fn from_u128(n: u128) -> Option<Self> {
+    Some(Scientific::from(n))
+  }
+
+  #[inline]
+  fn from_f32(n: f32) -> Option<Self> {
+    Scientific::try_from(n).ok()
+  }
+
+  #[inline]
+  fn from_f64(n: f64) -> Option<Self> {
+    Scientific::try_from(n).ok()
+  }
+}
+
+// The value is truncated towards zero (like the conversion of floats).
+macro_rules! to_integer {
+  ($name:ident, $ty:ident) => {
+    fn $name(&self) -> Option<$ty> {
+      $ty::try_from(&self.truncate(Precision::INTEGER)).ok()
+    }
+  };
+}
+
+impl ToPrimitive for Scientific {
+  to_integer!(to_isize, isize);
+  to_integer!(to_i8, i8);
+  to_integer!(to_i16, i16);
+  to_integer!(to_i32, i32);
+  to_integer!(to_i64, i64);
+  to_integer!(to_i128, i128);
+  to_integer!(to_usize, usize);
+  to_integer!(to_u8, u8);
+  to_integer!(to_u16, u16);
+  to_integer!(to_u32, u32);
+  to_integer!(to_u64, u64);
+  to_integer!(to_u128, u128);
+
+  #[inline]
+  fn to_f32(&self) -> Option<f32> {
+    Some(f32::from(self))
+  }
+
+  #[inline]
+  fn to_f64(&self) -> Option<f64> {
+    Some(f64::from(self))
+  }
+}
+
+impl num_traits::NumCast for Scientific {
+  fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+    // floats are also converted into integers (truncated), use them only if they are exact
+    let float = n.to_f64();
+    #[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+    if let Some(i) = n
+      .to_i128()
+      .filter(|i| float.map_or(true, |f| *i as f64 == f))
+    {
+      Some(From::from(i))
+    } else if let Some(i) = n
+      .to_u128()
+      .filter(|i| float.map_or(true, |f| *i as f64 == f))
+    {
+      Some(From::from(i))
+    } else {
+      float.and_then(|f| Scientific::try_from(f).ok())
+    }
+  }
+}
+
+impl CheckedAdd for Scientific {
+  #[inline]
+  fn checked_add(&self, v: &Self) -> Option<Self> {
+    Some(self + v)
+  }
+}
+
+impl CheckedSub for Scientific {
+  #[inline]
+  fn checked_sub(&self, v: &Self) -> Option<Self> {
+    Some(self - v)
+  }
+}
+
+impl CheckedMul for Scientific {
+  #[inline]
+  fn checked_mul(&self, v: &Self) -> Option<Self> {
+    Some(self * v)
+  }
+}
+
+impl CheckedDiv for Scientific {
+  /// Calculate the division with the precision [`Precision::F64`] (like the operator `/`),
+  /// returns `None` if `v` is zero.
+  #[inline]
+  fn checked_div(&self, v: &Self) -> Option<Self> {
+    self.div_truncate(v, Precision::F64).ok()
+  }
+}
+
+impl CheckedRem for Scientific {
+  #[inline]
+  fn checked_rem(&self, v: &Self) -> Option<Self> {
+    self.div_rem(v).ok().map(|(_, rem)| rem)
+  }
+}
+
+impl Euclid for Scientific {
+  /// # Panics
+  ///
+  /// Will panic if `v` is zero.
+  #[inline]
+  fn div_euclid(&self, v: &Self) -> Self {
+    Scientific::div_euclid(self, v).expect("attempt to divide by zero")
+  }
+
+  /// # Panics
+  ///
+  /// Will panic if `v` is zero.
+  #[inline]
+  fn rem_euclid(&self, v: &Self) -> Self {
+    Scientific::rem_euclid(self, v)
+      .expect("attempt to calculate the remainder with a divisor of zero")
+  }
+}
+
+impl CheckedEuclid for Scientific {
+  #[inline]
+  fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+    Scientific::div_euclid(self, v).ok()
+  }
+
+  #[inline]
+  fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+    Scientific::rem_euclid(self, v).ok()
+  }
+}
+
+impl CheckedNeg for Scientific {
+  #[inline]
+  fn checked_neg(&self) -> Option<Self> {
+    Some(-self)
+  }
+}
+
+impl Pow<usize> for Scientific {
+  type Output = Scientific;
+
+  #[inline]
+  fn pow(self, rhs: usize) -> Self::Output {
+    self.powi(rhs)
+  }
+}
+
+impl Pow<usize> for &Scientific {
+  type Output = Scientific;
+
+  #[inline]
+  fn pow(self, rhs: usize) -> Self::Output {
+    self.powi(rhs)
+  }
+}
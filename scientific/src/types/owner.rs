@@ -62,4 +62,23 @@ impl Owner {
       },
     }
   }
+
+  // the data if there is no other owner
+  #[cfg(not(feature = "arc"))]
+  #[inline]
+  pub(crate) fn vec_mut(&mut self) -> Option<&mut Vec<u8>> {
+    match self {
+      Owner::None => None,
+      Owner::Vec(v) => Rc::get_mut(v),
+    }
+  }
+
+  #[cfg(feature = "arc")]
+  #[inline]
+  pub(crate) fn vec_mut(&mut self) -> Option<&mut Vec<u8>> {
+    match self {
+      Owner::None => None,
+      Owner::Vec(v) => Arc::get_mut(v),
+    }
+  }
 }
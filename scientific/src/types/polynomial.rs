@@ -0,0 +1,495 @@
+use crate::types::precision::Precision;
+use crate::types::rounding::Rounding;
+use crate::types::scientific::Scientific;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Polynomial with [`Scientific`] coefficients.
+///
+/// The coefficients are stored with the lowest degree first, so `[c0, c1, c2]` is
+/// `c0 + c1*x + c2*x^2`. Trailing zeros are removed, the zero polynomial has no coefficients.
+///
+/// ```
+/// # use scientific::{Digits, Polynomial, Rounding, Scientific};
+/// # use std::str::FromStr;
+/// // x^2 - 2
+/// let p = Polynomial::new(vec![Scientific::from(-2), Scientific::ZERO, Scientific::ONE]);
+/// assert_eq!(p.eval(&Scientific::from(3)), Scientific::from(7));
+/// assert_eq!(
+///   p.real_roots(Digits(5), Rounding::RoundHalfToEven),
+///   vec![Scientific::from_str("-1.4142").unwrap(), Scientific::from_str("1.4142").unwrap()]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[must_use]
+#[cfg_attr(no_re_export, allow(dead_code))]
+pub struct Polynomial {
+  coefficients: Vec<Scientific>,
+}
+
+#[cfg_attr(no_re_export, allow(dead_code))]
+impl Polynomial {
+  /// Create a polynomial from its coefficients, the lowest degree first.
+  #[inline]
+  pub fn new(mut coefficients: Vec<Scientific>) -> Polynomial {
+    trim(&mut coefficients);
+    Polynomial { coefficients }
+  }
+
+  /// Returns the coefficients, the lowest degree first.
+  #[inline]
+  pub fn coefficients(&self) -> &[Scientific] {
+    &self.coefficients
+  }
+
+  /// Consume the polynomial and return its coefficients, the lowest degree first.
+  #[inline]
+  #[must_use]
+  pub fn into_coefficients(self) -> Vec<Scientific> {
+    self.coefficients
+  }
+
+  /// Returns the degree, `None` for the zero polynomial.
+  #[inline]
+  #[must_use]
+  pub fn degree(&self) -> Option<usize> {
+    self.coefficients.len().checked_sub(1)
+  }
+
+  /// Returns true for the zero polynomial.
+  #[inline]
+  #[must_use]
+  pub fn is_zero(&self) -> bool {
+    self.coefficients.is_empty()
+  }
+
+  /// Evaluate the polynomial exactly (with Horner's method).
+  #[inline]
+  pub fn eval(&self, x: &Scientific) -> Scientific {
+    eval(&self.coefficients, x)
+  }
+
+  /// Evaluate the polynomial with Horner's method, with included rpsp (Rounding to Prepare for
+  /// Shorter Precision).
+  ///
+  /// Each step is a [`mul_add_rpsp`](Scientific::mul_add_rpsp), so the rounding errors of
+  /// the earlier steps are multiplied with powers of `x`. Use [`eval`](Polynomial::eval) and
+  /// round the result if it must be correctly rounded.
+  pub fn eval_rpsp(&self, x: &Scientific, precision: Precision) -> Scientific {
+    let mut iter = self.coefficients.iter().rev();
+    let Some(first) = iter.next() else {
+      return Scientific::ZERO;
+    };
+    iter.fold(first.round_rpsp(precision), |result, c| {
+      result.mul_add_rpsp(x, c, precision)
+    })
+  }
+
+  /// Calculate the derivative.
+  #[inline]
+  pub fn derivative(&self) -> Polynomial {
+    Polynomial::new(derivative(&self.coefficients))
+  }
+
+  /// Find the distinct real roots, in ascending order.
+  ///
+  /// The roots are isolated with a Sturm sequence (which is calculated exactly) and refined with
+  /// Newton's method and bisection. Each root is correctly rounded to the given precision.
+  ///
+  /// Multiple roots are returned only once. The zero polynomial has no isolated roots,
+  /// so an empty list is returned for it.
+  #[must_use]
+  pub fn real_roots(&self, precision: Precision, rounding: Rounding) -> Vec<Scientific> {
+    let mut roots = Vec::new();
+    if self.coefficients.len() < 2 {
+      return roots;
+    }
+    let mut p = primitive(self.coefficients.clone());
+    // divide by x^zeros
+    let zeros = p.iter().take_while(|c| c.is_zero()).count();
+    p.drain(..zeros);
+    if p.len() < 2 {
+      roots.push(Scientific::ZERO);
+      return roots;
+    }
+
+    let mut sequence = sturm_sequence(p);
+    if sequence[sequence.len() - 1].len() > 1 {
+      // the last element is gcd(p, p'), the quotient has the same roots but all of them are simple
+      let (quotient, _) = pseudo_divide(&sequence[0], &sequence[sequence.len() - 1]);
+      sequence = sturm_sequence(primitive(quotient));
+    }
+    let p = &sequence[0];
+    let slope = derivative(p);
+
+    // all roots are in (-bound, bound), see Fujiwara's bound: 2 * max |a(n - i) / a(n)|^(1 / i)
+    let lead = &p[p.len() - 1];
+    let exponent = p[..p.len() - 1]
+      .iter()
+      .rev()
+      .zip(1..)
+      .filter(|(c, _)| !c.is_zero())
+      // |c / lead| < 10^t, so |c / lead|^(1 / i) < 10^ceil(t / i)
+      .map(|(c, i)| -(lead.exponent0() - c.exponent0() - 1).div_euclid(i))
+      .max()
+      .unwrap_or(0);
+    let bound = &Scientific::from(2) << exponent;
+    let low = -&bound;
+
+    let zero_variations = variations(&sequence, &Scientific::ZERO);
+    let mut negative = Vec::new();
+    let mut positive = Vec::new();
+    isolate(
+      &sequence,
+      (&low, variations(&sequence, &low)),
+      (&Scientific::ZERO, zero_variations),
+      &mut negative,
+    );
+    isolate(
+      &sequence,
+      (&Scientific::ZERO, zero_variations),
+      (&bound, variations(&sequence, &bound)),
+      &mut positive,
+    );
+
+    for (lo, hi) in negative {
+      roots.push(refine(p, &slope, lo, hi, precision, rounding));
+    }
+    if zeros > 0 {
+      roots.push(Scientific::ZERO);
+    }
+    for (lo, hi) in positive {
+      roots.push(refine(p, &slope, lo, hi, precision, rounding));
+    }
+    roots
+  }
+}
+
+impl From<Vec<Scientific>> for Polynomial {
+  #[inline]
+  fn from(coefficients: Vec<Scientific>) -> Self {
+    Polynomial::new(coefficients)
+  }
+}
+
+impl Add for &Polynomial {
+  type Output = Polynomial;
+
+  fn add(self, rhs: Self) -> Self::Output {
+    let (mut coefficients, other) = if self.coefficients.len() >= rhs.coefficients.len() {
+      (self.coefficients.clone(), &rhs.coefficients)
+    } else {
+      (rhs.coefficients.clone(), &self.coefficients)
+    };
+    for (c, other) in coefficients.iter_mut().zip(other.iter()) {
+      *c += other;
+    }
+    Polynomial::new(coefficients)
+  }
+}
+
+impl Sub for &Polynomial {
+  type Output = Polynomial;
+
+  fn sub(self, rhs: Self) -> Self::Output {
+    let mut coefficients = self.coefficients.clone();
+    if coefficients.len() < rhs.coefficients.len() {
+      coefficients.resize(rhs.coefficients.len(), Scientific::ZERO);
+    }
+    for (c, other) in coefficients.iter_mut().zip(rhs.coefficients.iter()) {
+      *c -= other;
+    }
+    Polynomial::new(coefficients)
+  }
+}
+
+impl Mul for &Polynomial {
+  type Output = Polynomial;
+
+  fn mul(self, rhs: Self) -> Self::Output {
+    if self.is_zero() || rhs.is_zero() {
+      return Polynomial::default();
+    }
+    let len = self.coefficients.len() + rhs.coefficients.len() - 1;
+    let mut coefficients = vec![Scientific::ZERO; len];
+    for (i, a) in self.coefficients.iter().enumerate() {
+      for (j, b) in rhs.coefficients.iter().enumerate() {
+        coefficients[i + j] += &(a * b);
+      }
+    }
+    Polynomial::new(coefficients)
+  }
+}
+
+impl Neg for &Polynomial {
+  type Output = Polynomial;
+
+  #[inline]
+  fn neg(self) -> Self::Output {
+    Polynomial {
+      coefficients: self.coefficients.iter().map(|c| -c).collect(),
+    }
+  }
+}
+
+fn trim(coefficients: &mut Vec<Scientific>) {
+  while coefficients.last().map_or(false, Scientific::is_zero) {
+    coefficients.pop();
+  }
+}
+
+fn eval(coefficients: &[Scientific], x: &Scientific) -> Scientific {
+  coefficients
+    .iter()
+    .rev()
+    .fold(Scientific::ZERO, |result, c| &(&result * x) + c)
+}
+
+fn derivative(coefficients: &[Scientific]) -> Vec<Scientific> {
+  coefficients
+    .iter()
+    .enumerate()
+    .skip(1)
+    .map(|(i, c)| c * i)
+    .collect()
+}
+
+// (a + b) / 2, exactly
+fn midpoint(a: &Scientific, b: &Scientific) -> Scientific {
+  &(&(a + b) * 5) >> 1_isize
+}
+
+// a positive multiple of the polynomial (which must not be zero) with coprime integer coefficients
+fn primitive(mut coefficients: Vec<Scientific>) -> Vec<Scientific> {
+  let exponent = coefficients
+    .iter()
+    .filter(|c| !c.is_zero())
+    .map(Scientific::exponent)
+    .min()
+    .unwrap_or(0);
+  let mut content = Scientific::ZERO;
+  for c in coefficients.iter_mut() {
+    *c = &*c >> exponent;
+    content = content.gcd(c).expect("the coefficients are integers");
+  }
+  for c in coefficients.iter_mut() {
+    *c = c
+      .div_truncate(&content, Precision::INTEGER)
+      .expect("the content is not zero");
+  }
+  coefficients
+}
+
+// (q, r) with s * a = q * b + r for some positive s, b must not be zero
+fn pseudo_divide(a: &[Scientific], b: &[Scientific]) -> (Vec<Scientific>, Vec<Scientific>) {
+  let lead = &b[b.len() - 1];
+  let scale = lead.abs();
+  let mut quotient = vec![Scientific::ZERO; (a.len() + 1).saturating_sub(b.len())];
+  let mut remainder = a.to_vec();
+  while remainder.len() >= b.len() {
+    let shift = remainder.len() - b.len();
+    // scale * last - factor * lead = 0
+    let mut factor = remainder[remainder.len() - 1].clone();
+    if lead.is_sign_negative() {
+      factor.neg_assign();
+    }
+    for c in remainder.iter_mut().chain(quotient.iter_mut()) {
+      *c = &*c * &scale;
+    }
+    quotient[shift] += &factor;
+    for (c, b) in remainder[shift..].iter_mut().zip(b.iter()) {
+      *c -= &(&factor * b);
+    }
+    remainder.pop();
+    trim(&mut remainder);
+  }
+  (quotient, remainder)
+}
+
+// the Sturm sequence of p (which must not be constant), up to positive factors
+fn sturm_sequence(p: Vec<Scientific>) -> Vec<Vec<Scientific>> {
+  let derivative = primitive(derivative(&p));
+  let mut sequence = vec![p, derivative];
+  loop {
+    let len = sequence.len();
+    let (_, mut remainder) = pseudo_divide(&sequence[len - 2], &sequence[len - 1]);
+    if remainder.is_empty() {
+      return sequence;
+    }
+    for c in remainder.iter_mut() {
+      c.neg_assign();
+    }
+    sequence.push(primitive(remainder));
+  }
+}
+
+// the number of sign changes of the Sturm sequence at x
+fn variations(sequence: &[Vec<Scientific>], x: &Scientific) -> usize {
+  let mut count = 0;
+  let mut last = Ordering::Equal;
+  for p in sequence {
+    let sign = eval(p, x).cmp(&Scientific::ZERO);
+    if sign != Ordering::Equal {
+      if last != Ordering::Equal && sign != last {
+        count += 1;
+      }
+      last = sign;
+    }
+  }
+  count
+}
+
+// collect intervals (lo, hi] with exactly one root each, with the variations at lo and hi
+fn isolate(
+  sequence: &[Vec<Scientific>],
+  (lo, lo_variations): (&Scientific, usize),
+  (hi, hi_variations): (&Scientific, usize),
+  intervals: &mut Vec<(Scientific, Scientific)>,
+) {
+  match lo_variations - hi_variations {
+    0 => {}
+    1 => intervals.push((lo.clone(), hi.clone())),
+    _ => {
+      let mid = midpoint(lo, hi);
+      let mid_variations = variations(sequence, &mid);
+      isolate(
+        sequence,
+        (lo, lo_variations),
+        (&mid, mid_variations),
+        intervals,
+      );
+      isolate(
+        sequence,
+        (&mid, mid_variations),
+        (hi, hi_variations),
+        intervals,
+      );
+    }
+  }
+}
+
+// the exponent of the last digit kept by the precision for all values in (lo, hi),
+// None if it is unknown
+fn grid_exponent(lo: &Scientific, hi: &Scientific, precision: Precision) -> Option<isize> {
+  match precision {
+    Precision::Decimals(decimals) => Some(-decimals),
+    Precision::Digits(digits) => {
+      if lo.is_sign_positive() && !lo.is_zero() {
+        Some(lo.exponent0() - digits)
+      } else if hi.is_sign_negative() && !hi.is_zero() {
+        Some(hi.exponent0() - digits)
+      } else {
+        None
+      }
+    }
+  }
+}
+
+// the only root of p in (lo, hi], rounded
+fn refine(
+  p: &[Scientific],
+  slope: &[Scientific],
+  mut lo: Scientific,
+  mut hi: Scientific,
+  precision: Precision,
+  rounding: Rounding,
+) -> Scientific {
+  let hi_sign = eval(p, &hi).cmp(&Scientific::ZERO);
+  if hi_sign == Ordering::Equal {
+    return hi.round(precision, rounding);
+  }
+  // the root is in (lo, hi), the sign of p is hi_sign above and the opposite below it;
+  // moves lo or hi to x and returns true if x is the root
+  let narrow = |x: &Scientific, lo: &mut Scientific, hi: &mut Scientific| {
+    let sign = eval(p, x).cmp(&Scientific::ZERO);
+    if sign == hi_sign {
+      *hi = x.clone();
+    } else if sign != Ordering::Equal {
+      *lo = x.clone();
+    }
+    sign == Ordering::Equal
+  };
+
+  // the start of the next Newton step, which is kept if the bracket shrinks too slowly
+  let mut guess = midpoint(&lo, &hi);
+  let mut bisect = false;
+  loop {
+    let width = &hi - &lo;
+    let grid = grid_exponent(&lo, &hi, precision);
+    if let Some(exponent) = grid {
+      if &width * 20 < &Scientific::ONE << exponent {
+        return finish(p, hi_sign, lo, hi, exponent, precision, rounding);
+      }
+    }
+    // all points are truncated to avoid growing numbers of digits
+    let decimals = grid.map(|exponent| Precision::Decimals(3 - exponent));
+
+    // a Newton step, bisection if it leaves the bracket or the bracket shrinks too slowly
+    let newton = match decimals {
+      Some(decimals) if !bisect => eval(p, &guess)
+        .div_rpsp(&eval(slope, &guess), decimals)
+        .ok()
+        .map(|step| (&guess - &step).truncate(decimals))
+        .filter(|next| &lo < next && next < &hi),
+      _ => None,
+    };
+    let x = match &newton {
+      Some(next) => next.clone(),
+      None => match decimals {
+        Some(decimals) => midpoint(&lo, &hi).truncate(decimals),
+        None => midpoint(&lo, &hi),
+      },
+    };
+    if narrow(&x, &mut lo, &mut hi) {
+      return x.round(precision, rounding);
+    }
+    if let (Some(exponent), Some(next)) = (grid, newton) {
+      // a Newton point is usually very close to the root, so try to move the other end, too
+      let delta = &Scientific::ONE << (exponent - 2);
+      let probe = if next == lo {
+        &next + &delta
+      } else {
+        &next - &delta
+      };
+      if lo < probe && probe < hi && narrow(&probe, &mut lo, &mut hi) {
+        return probe.round(precision, rounding);
+      }
+      guess = next;
+    } else if !bisect {
+      // the Newton step failed, restart it closer to the root
+      guess = x;
+    }
+    bisect = &(&hi - &lo) * 2 > width;
+  }
+}
+
+// the root in (lo, hi) rounded, the interval is shorter than 5 * 10^(exponent - 2) which is the
+// distance of the possible boundaries between values with different roundings (the values kept by
+// the precision, with one more digit and the midpoints between them), so it contains at most one
+fn finish(
+  p: &[Scientific],
+  hi_sign: Ordering,
+  mut lo: Scientific,
+  mut hi: Scientific,
+  exponent: isize,
+  precision: Precision,
+  rounding: Rounding,
+) -> Scientific {
+  // the first multiple of 5 * 10^(exponent - 2) above lo
+  let twice: Scientific = &lo * 2;
+  let steps = (&twice >> (exponent - 1)).round(Precision::INTEGER, Rounding::RoundDown);
+  let boundary: Scientific = &(&steps + 1) * 5;
+  let boundary = &boundary << (exponent - 2);
+  if boundary < hi {
+    let sign = eval(p, &boundary).cmp(&Scientific::ZERO);
+    if sign == Ordering::Equal {
+      return boundary.round(precision, rounding);
+    } else if sign == hi_sign {
+      hi = boundary;
+    } else {
+      lo = boundary;
+    }
+  }
+  midpoint(&lo, &hi).round(precision, rounding)
+}
@@ -2,6 +2,7 @@ use crate::types::rounding::Rounding;
 use crate::types::rounding_rpsp::RPSP;
 
 #[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy)]
 pub(crate) enum RoundingMode {
   Rounding(Rounding),
   RPSP(RPSP),
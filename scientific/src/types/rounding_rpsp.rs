@@ -1,4 +1,5 @@
 #[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy)]
 pub(crate) struct RPSP;
 
 impl RPSP {
@@ -69,6 +69,11 @@ impl Sci {
     self.len == 0
   }
 
+  #[inline]
+  pub(crate) fn is_integer(&self) -> bool {
+    self.is_zero() || self.exponent >= 0
+  }
+
   #[inline]
   pub(crate) fn exponent0(&self) -> isize {
     self.exponent + self.len
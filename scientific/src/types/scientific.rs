@@ -1,3 +1,4 @@
+use crate::types::accumulator::Accumulator;
 use crate::types::conversion_error::ConversionError;
 use crate::types::error::Error;
 use crate::types::precision::Precision;
@@ -11,8 +12,9 @@ use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
 use core::ops::{
-  Add, AddAssign, Mul, MulAssign, Neg, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+  Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 use core::str::FromStr;
 
@@ -49,6 +51,9 @@ impl Scientific {
   }
 
   /// Convert a value into a compressed binary format.
+  ///
+  /// The result is canonical, i.e. there is exactly one representation of each value
+  /// (see [`from_bytes_strict`](Scientific::from_bytes_strict)).
   #[inline]
   #[must_use]
   pub fn to_bytes(&self) -> Vec<u8> {
@@ -56,13 +61,388 @@ impl Scientific {
   }
 
   /// Convert a compressed binary format into a value.
+  ///
+  /// Please be aware that some values have multiple representations (e.g. an exponent
+  /// stored with more bytes than necessary), use [`from_bytes_strict`](Scientific::from_bytes_strict)
+  /// to only accept the canonical one.
   #[inline]
   pub fn from_bytes(bytes: &[u8]) -> Result<Scientific, ConversionError> {
     Ok(Scientific {
-      inner: Sci::from_bytes(bytes)?,
+      inner: Sci::from_bytes::<false>(bytes)?,
     })
   }
 
+  /// Convert a compressed binary format into a value, only accepting the canonical representation.
+  ///
+  /// Only the exact bytes created by [`to_bytes`](Scientific::to_bytes) are accepted, thus
+  /// two different byte strings never result in the same value.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::ParseError`] if the bytes are invalid or not canonical.
+  #[inline]
+  pub fn from_bytes_strict(bytes: &[u8]) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_bytes::<true>(bytes)?,
+    })
+  }
+
+  /// Convert a value into a binary format which preserves the order.
+  ///
+  /// Comparing the bytes lexicographically results in the same order as comparing the values,
+  /// this allows to use the value as a key in a key-value store.
+  ///
+  /// The format is prefix free, i.e. it can be concatenated with other keys and still
+  /// preserves the order.
+  ///
+  /// The result is larger than the one of [`to_bytes`](Scientific::to_bytes).
+  #[inline]
+  #[must_use]
+  pub fn to_sortable_bytes(&self) -> Vec<u8> {
+    self.inner.to_sortable_bytes()
+  }
+
+  /// Convert the binary format created by [`to_sortable_bytes`](Scientific::to_sortable_bytes) into a value.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::ParseError`] if the bytes are not exactly one value
+  /// and [`ConversionError::ExponentTooLargeForThisPlatform`] if the exponent doesn't fit into an `isize`.
+  #[inline]
+  pub fn from_sortable_bytes(bytes: &[u8]) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_sortable_bytes(bytes)?,
+    })
+  }
+
+  /// Convert a [`f64`] into a value, using the exact binary value.
+  ///
+  /// In contrast to `TryFrom<f64>`, which uses the shortest representation (`0.1` results in `0.1`),
+  /// this results in the exact value of the float (`0.1` results in `0.1000000000000000055511151231257827021181583404541015625`).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::FloatIsNotFinite`] if the float is infinite or NaN.
+  pub fn from_f64_exact(value: f64) -> Result<Scientific, ConversionError> {
+    if value.is_finite() {
+      Ok(Scientific {
+        inner: Sci::from_f64_exact(value),
+      })
+    } else {
+      Err(ConversionError::FloatIsNotFinite)
+    }
+  }
+
+  /// Append the value in a length delimited format.
+  ///
+  /// The format is the length of the [compressed format](Scientific::to_bytes) (as an unsigned LEB128)
+  /// followed by it, this allows to embed it into a larger stream of bytes.
+  #[inline]
+  pub fn encode_into<E: Extend<u8>>(&self, target: &mut E) {
+    self.inner.encode_into(target);
+  }
+
+  /// Convert the length delimited format at the start of the bytes into a value.
+  ///
+  /// Returns the value and the number of bytes used, see [`encode_into`](Scientific::encode_into).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::ParseError`] if the bytes do not start with a valid value.
+  #[inline]
+  pub fn decode_prefix(bytes: &[u8]) -> Result<(Scientific, usize), ConversionError> {
+    let (inner, len) = Sci::decode_prefix(bytes)?;
+    Ok((Scientific { inner }, len))
+  }
+
+  /// Write the value in the length delimited format, see [`encode_into`](Scientific::encode_into).
+  #[cfg(feature = "std")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+  #[inline]
+  pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+    self.inner.write_to(writer)
+  }
+
+  /// Read a value in the length delimited format, see [`encode_into`](Scientific::encode_into).
+  ///
+  /// Exactly the bytes of the value are consumed from the reader.
+  ///
+  /// # Errors
+  ///
+  /// Will return an error of the kind [`InvalidData`](std::io::ErrorKind::InvalidData) if the data
+  /// is not a valid value, or the error of the reader.
+  #[cfg(feature = "std")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+  #[inline]
+  pub fn read_from<R: std::io::Read>(reader: &mut R) -> std::io::Result<Scientific> {
+    Ok(Scientific {
+      inner: Sci::read_from(reader)?,
+    })
+  }
+
+  /// Convert a value into the binary format of the Postgres `NUMERIC` type.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::NumberTooLarge`] if the value is not representable as `NUMERIC`
+  /// (the exponent is too large or too small).
+  #[cfg(feature = "postgres")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+  #[inline]
+  pub fn to_postgres_numeric(&self) -> Result<Vec<u8>, ConversionError> {
+    self.inner.to_postgres_numeric()
+  }
+
+  /// Convert the binary format of the Postgres `NUMERIC` type into a value.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::FloatIsNotFinite`] for `NaN`, `Infinity` and `-Infinity`
+  /// and [`ConversionError::ParseError`] if the bytes are invalid.
+  #[cfg(feature = "postgres")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+  #[inline]
+  pub fn from_postgres_numeric(bytes: &[u8]) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_postgres_numeric(bytes)?,
+    })
+  }
+
+  /// Convert a value into a CBOR decimal fraction (tag 4, see RFC 8949, section 3.4.4).
+  ///
+  /// The mantissa is encoded as an integer, or as a bignum (tag 2 or 3) if it doesn't fit
+  /// into 64 bits. Zero is encoded as `4([0, 0])`.
+  #[inline]
+  #[must_use]
+  pub fn to_cbor_decimal(&self) -> Vec<u8> {
+    self.inner.to_cbor_decimal()
+  }
+
+  /// Convert a CBOR decimal fraction (tag 4) into a value, see [`to_cbor_decimal`](Scientific::to_cbor_decimal).
+  ///
+  /// The bytes must contain exactly one decimal fraction. The mantissa may be an integer or a
+  /// bignum (tag 2 or 3), indefinite lengths are not supported.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::ParseError`] if the bytes are not a valid decimal fraction
+  /// and [`ConversionError::ExponentTooLargeForThisPlatform`] if the exponent doesn't fit into an `isize`.
+  #[inline]
+  pub fn from_cbor_decimal(bytes: &[u8]) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_cbor_decimal(bytes)?,
+    })
+  }
+
+  /// Convert a value into a BSON `Decimal128` (an IEEE 754-2008 decimal128 in the binary integer
+  /// decimal encoding, little endian).
+  ///
+  /// The value is rounded to 34 digits (or less if it is very small), a too large exponent is
+  /// clamped by adding zeroes to the coefficient if possible.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::NumberTooLarge`] if the value is too large to be represented.
+  #[inline]
+  pub fn to_bson_decimal128(&self, rounding: Rounding) -> Result<[u8; 16], ConversionError> {
+    self.inner.to_bson_decimal128(rounding)
+  }
+
+  /// Convert a BSON `Decimal128` into a value, see [`to_bson_decimal128`](Scientific::to_bson_decimal128).
+  ///
+  /// Non-canonical values (with a coefficient larger than 34 digits) are treated as zero.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::FloatIsNotFinite`] for `NaN`, `Infinity` and `-Infinity`.
+  #[inline]
+  pub fn from_bson_decimal128(bytes: [u8; 16]) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_bson_decimal128(bytes)?,
+    })
+  }
+
+  /// Convert a value into the string of a protobuf `google.type.Decimal`.
+  ///
+  /// This is the same as [`to_string`](alloc::string::ToString::to_string), which produces the
+  /// normalized form (no leading `+`, a lowercase `e` and no trailing `.`).
+  #[inline]
+  #[must_use]
+  pub fn to_google_decimal(&self) -> String {
+    self.to_string()
+  }
+
+  /// Convert the string of a protobuf `google.type.Decimal` into a value.
+  ///
+  /// The grammar of `google.type.Decimal` is the same as the one accepted by
+  /// [`from_string`](Scientific::from_string):
+  ///
+  /// ```text
+  /// Decimal     ::= [Sign] Significand [Exponent]
+  /// Sign        ::= '+' | '-'
+  /// Significand ::= Digits | Digits '.' | [Digits] '.' Digits
+  /// Exponent    ::= ('e' | 'E') [Sign] Digits
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::ParseError`] if the string is invalid or the exponent does not
+  /// fit into an `isize`.
+  #[inline]
+  pub fn from_google_decimal(value: &str) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_string(value.to_string())?,
+    })
+  }
+
+  /// Convert a value into a scaled integer as used by the Arrow type `Decimal128`.
+  ///
+  /// The value is rounded to `scale` decimals and then multiplied by `10^scale`, the result must
+  /// not have more than `precision` digits (at most 38).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::NumberTooLarge`] if the rounded value has more than
+  /// `precision` digits and [`ConversionError::PrecisionTooLarge`] if `precision` is above 38.
+  #[inline]
+  pub fn to_decimal128(
+    &self,
+    precision: u8,
+    scale: i8,
+    rounding: Rounding,
+  ) -> Result<i128, ConversionError> {
+    self.inner.to_decimal128(precision, scale, rounding)
+  }
+
+  /// Convert a scaled integer as used by the Arrow type `Decimal128` into a value.
+  ///
+  /// The result is `value * 10^-scale`.
+  #[inline]
+  pub fn from_scaled_i128(value: i128, scale: i8) -> Scientific {
+    let mut result = Scientific::from(value);
+    result.inner.shr_assign(isize::from(scale));
+    result
+  }
+
+  /// Convert a value into a scaled integer as used by the Arrow type `Decimal256`.
+  ///
+  /// The result is a 256-bit two's complement integer as four `u64` in little endian order.
+  /// Otherwise this is the same as [`to_decimal128`](Scientific::to_decimal128), except that the
+  /// precision can be up to 76.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::NumberTooLarge`] if the rounded value has more than
+  /// `precision` digits and [`ConversionError::PrecisionTooLarge`] if `precision` is above 76.
+  #[inline]
+  pub fn to_decimal256(
+    &self,
+    precision: u8,
+    scale: i8,
+    rounding: Rounding,
+  ) -> Result<[u64; 4], ConversionError> {
+    self.inner.to_decimal256(precision, scale, rounding)
+  }
+
+  /// Convert a scaled integer as used by the Arrow type `Decimal256` into a value.
+  ///
+  /// The value is a 256-bit two's complement integer as four `u64` in little endian order, see
+  /// [`to_decimal256`](Scientific::to_decimal256). The result is `value * 10^-scale`.
+  #[inline]
+  pub fn from_scaled_i256(value: [u64; 4], scale: i8) -> Scientific {
+    Scientific {
+      inner: Sci::from_scaled_i256(value, scale),
+    }
+  }
+
+  /// Convert the absolute value of an integer into little endian `u64` limbs.
+  ///
+  /// Zero is returned as an empty vector, the sign is not included
+  /// (see [`is_sign_negative`](Scientific::is_sign_negative)).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::NumberIsNotAnInteger`] if the value has decimals
+  /// and [`ConversionError::NumberTooLarge`] if it has more than 100000000 digits.
+  #[inline]
+  pub fn to_le_limbs(&self) -> Result<Vec<u64>, ConversionError> {
+    self.inner.to_le_limbs()
+  }
+
+  /// Convert little endian `u64` limbs into an integer, negated if `negative` is true.
+  #[inline]
+  pub fn from_le_limbs(negative: bool, limbs: &[u64]) -> Scientific {
+    Scientific {
+      inner: Sci::from_le_limbs(negative, limbs, 0),
+    }
+  }
+
+  /// Convert an integer into a string in the given radix.
+  ///
+  /// Digits above 9 are written as lowercase letters, negative numbers are prefixed with `-`.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::NumberIsNotAnInteger`] if the value has decimals
+  /// and [`ConversionError::NumberTooLarge`] if it has more than 100000000 digits.
+  ///
+  /// # Panics
+  ///
+  /// Will panic if `radix` is not in the range from 2 to 36.
+  #[inline]
+  pub fn to_str_radix(&self, radix: u32) -> Result<String, ConversionError> {
+    self.inner.to_str_radix(radix)
+  }
+
+  /// Convert a string with an integer in the given radix into a value.
+  ///
+  /// The string may start with `+` or `-`, digits above 9 are letters (upper or lower case).
+  /// Only integers are accepted, also for radix 10 (use [`from_str`](core::str::FromStr::from_str)
+  /// for decimal numbers with a point or an exponent).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::ParseError`] if the string is empty or contains invalid characters.
+  ///
+  /// # Panics
+  ///
+  /// Will panic if `radix` is not in the range from 2 to 36.
+  #[inline]
+  pub fn from_str_radix_integer(src: &str, radix: u32) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_str_radix_integer(src, radix)?,
+    })
+  }
+
+  /// Convert a hexadecimal floating-point literal (as written by `printf("%a")` in C99) into its
+  /// exact decimal value.
+  ///
+  /// The format is `[+-]0x<hex digits>[.<hex digits>][p[+-]<decimal exponent>]`, e.g. `0x1.8p+3`
+  /// is 12. The prefix `0x` and the letters may also be uppercase.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::FloatIsNotFinite`] for `inf`, `infinity` and `nan`
+  /// and [`ConversionError::ParseError`] if the string is invalid or the exponent is outside
+  /// of the range from `-20000` to `20000`.
+  #[inline]
+  pub fn from_hex_float(value: &str) -> Result<Scientific, ConversionError> {
+    Ok(Scientific {
+      inner: Sci::from_hex_float(value)?,
+    })
+  }
+
+  /// Convert a value into a hexadecimal floating-point literal with `precision` hexadecimal digits
+  /// after the point (like `printf("%.*a")` in C99).
+  ///
+  /// The value is normalized to a leading `1` (zero is written as `0x0p+0`) and rounded half to even.
+  /// The result is exact if `precision` is large enough, see [`from_hex_float`](Scientific::from_hex_float).
+  #[inline]
+  #[must_use]
+  pub fn to_hex_float(&self, precision: usize) -> String {
+    self.inner.to_hex_float(precision)
+  }
+
   /// Return a reference to the mantissa.
   #[inline]
   #[must_use]
@@ -152,6 +532,407 @@ impl Scientific {
     Ok((Scientific { inner: d }, Scientific { inner: r }))
   }
 
+  /// Calculate the quotient of the Euclidean division.
+  ///
+  /// This is the integer `q` for which `self = q * rhs + r` with `0 <= r < |rhs|`
+  /// (see [`rem_euclid`](Scientific::rem_euclid)). Like [`div_rem`](Scientific::div_rem)
+  /// this also works for numbers with decimals.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::DivisionByZero`] if `rhs` is zero.
+  #[inline]
+  pub fn div_euclid(&self, rhs: &Scientific) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.div_rem_euclid(&rhs.inner)?.0,
+    })
+  }
+
+  /// Calculate the remainder of the Euclidean division, which is never negative
+  /// (`-7` and `2` results in `1`).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::DivisionByZero`] if `rhs` is zero.
+  #[inline]
+  pub fn rem_euclid(&self, rhs: &Scientific) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.div_rem_euclid(&rhs.inner)?.1,
+    })
+  }
+
+  /// Calculate the quotient of the division, rounded towards negative infinity.
+  ///
+  /// This is the integer `q` for which `self = q * rhs + r` where `r` has the sign of `rhs`
+  /// (see [`mod_floor`](Scientific::mod_floor)).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::DivisionByZero`] if `rhs` is zero.
+  #[inline]
+  pub fn div_floor(&self, rhs: &Scientific) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.div_rem_floor(&rhs.inner)?.0,
+    })
+  }
+
+  /// Calculate the modulo of the floored division, which has the sign of `rhs` like the `%`
+  /// operator in Python (`-7` and `2` results in `1`, `7` and `-2` results in `-1`).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::DivisionByZero`] if `rhs` is zero.
+  #[inline]
+  pub fn mod_floor(&self, rhs: &Scientific) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.div_rem_floor(&rhs.inner)?.1,
+    })
+  }
+
+  /// Convert the value into an exact fraction in lowest terms.
+  ///
+  /// Returns numerator and denominator, the denominator is always positive (`0.125` results in `1/8`).
+  #[inline]
+  pub fn to_fraction(&self) -> (Scientific, Scientific) {
+    let (numerator, denominator) = self.inner.to_fraction();
+    (
+      Scientific { inner: numerator },
+      Scientific { inner: denominator },
+    )
+  }
+
+  /// Calculate the terms of the (regular) continued fraction, at most `max_terms`.
+  ///
+  /// The first term is the value rounded towards negative infinity, all following terms are positive.
+  /// Since the value is a finite fraction the continued fraction ends after a finite number of terms.
+  #[inline]
+  #[must_use]
+  pub fn continued_fraction(&self, max_terms: usize) -> Vec<Scientific> {
+    self
+      .inner
+      .continued_fraction(max_terms)
+      .into_iter()
+      .map(|inner| Scientific { inner })
+      .collect()
+  }
+
+  /// Find the fraction closest to the value with a denominator not larger than `max_denominator`.
+  ///
+  /// Returns numerator and denominator in lowest terms (`3.14159265` with a maximum denominator of
+  /// 1000 results in `355/113`). The maximum denominator is truncated to an integer, values below 1
+  /// are treated as 1. If two fractions are equally close, the one with the smaller denominator is returned.
+  #[inline]
+  pub fn best_rational(&self, max_denominator: &Scientific) -> (Scientific, Scientific) {
+    let (numerator, denominator) = self.inner.best_rational(&max_denominator.inner);
+    (
+      Scientific { inner: numerator },
+      Scientific { inner: denominator },
+    )
+  }
+
+  /// Calculate the greatest common divisor of two integers.
+  ///
+  /// The result is never negative, it is zero only if both values are zero.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::NumberIsNotAnInteger`] if one of the values has decimals and
+  /// [`Error::NumberTooLarge`] if a value has more than 100000000 digits without the trailing zeros
+  /// common to both values.
+  #[inline]
+  pub fn gcd(&self, rhs: &Scientific) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.gcd(&rhs.inner)?,
+    })
+  }
+
+  /// Calculate the least common multiple of two integers.
+  ///
+  /// The result is never negative, it is zero if one of the values is zero.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::NumberIsNotAnInteger`] if one of the values has decimals and
+  /// [`Error::NumberTooLarge`] like [`gcd`](Scientific::gcd).
+  #[inline]
+  pub fn lcm(&self, rhs: &Scientific) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.lcm(&rhs.inner)?,
+    })
+  }
+
+  /// Calculate `self^exponent mod modulus` of integers.
+  ///
+  /// The result has the sign of the modulus (it is in the range `0..modulus` for a positive modulus).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::NumberIsNotAnInteger`] if one of the values has decimals,
+  /// [`Error::DivisionByZero`] if the modulus is zero, [`Error::NumberIsNegative`] if the exponent is negative
+  /// and [`Error::NumberTooLarge`] if one of the values has more than 100000000 digits.
+  #[inline]
+  pub fn mod_pow(&self, exponent: &Scientific, modulus: &Scientific) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.mod_pow(&exponent.inner, &modulus.inner)?,
+    })
+  }
+
+  /// Calculate the modular multiplicative inverse of an integer, i.e. `x` with `self * x mod modulus = 1`.
+  ///
+  /// Returns `None` if there is no inverse (the value and the modulus are not coprime), otherwise the
+  /// result has the sign of the modulus, like [`mod_pow`](Scientific::mod_pow).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::NumberIsNotAnInteger`] if one of the values has decimals,
+  /// [`Error::DivisionByZero`] if the modulus is zero and [`Error::NumberTooLarge`] if one of
+  /// the values has more than 100000000 digits.
+  #[inline]
+  pub fn mod_inverse(&self, modulus: &Scientific) -> Result<Option<Scientific>, Error> {
+    Ok(
+      self
+        .inner
+        .mod_inverse(&modulus.inner)?
+        .map(|inner| Scientific { inner }),
+    )
+  }
+
+  /// Test if the value is a prime number (Miller-Rabin).
+  ///
+  /// Values below 3317044064679887385961981 are tested with fixed bases which give an exact result.
+  /// Larger values are tested with `rounds` fixed bases (the smallest primes), a composite number
+  /// is reported as prime with a probability of at most `4^-rounds` (but numbers can be constructed
+  /// to fool fixed bases, use enough rounds if the value comes from an untrusted source).
+  ///
+  /// Values which are not integers or smaller than 2 are not prime.
+  #[inline]
+  #[must_use]
+  pub fn is_probable_prime(&self, rounds: usize) -> bool {
+    self.inner.is_probable_prime(rounds)
+  }
+
+  /// Find the smallest prime larger than the value.
+  ///
+  /// Primality is tested with [`is_probable_prime`](Scientific::is_probable_prime) with 20 rounds.
+  #[inline]
+  pub fn next_prime(&self) -> Scientific {
+    Scientific {
+      inner: self.inner.next_prime(),
+    }
+  }
+
+  /// Factorize the absolute value of an integer into primes.
+  ///
+  /// Returns the prime factors in ascending order with their multiplicity, zero and one result in
+  /// an empty vector (`-360` results in `[(2, 3), (3, 2), (5, 1)]`).
+  ///
+  /// Small factors are found by trial division, the others by Pollard's rho algorithm, which
+  /// needs about `sqrt(p)` steps to find the factor `p`.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`ConversionError::NumberIsNotAnInteger`] if the value has decimals.
+  pub fn factorize(&self) -> Result<Vec<(Scientific, usize)>, ConversionError> {
+    Ok(
+      self
+        .inner
+        .factorize()?
+        .into_iter()
+        .map(|(inner, count)| (Scientific { inner }, count))
+        .collect(),
+    )
+  }
+
+  /// Calculate the integer square root, returns the square root rounded down and the remainder.
+  ///
+  /// In contrast to [`sqrt_truncate`](Scientific::sqrt_truncate) only integer divisions are used.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::NumberIsNotAnInteger`] if the value has decimals and
+  /// [`Error::NumberIsNegative`] if the value is negative.
+  #[inline]
+  pub fn isqrt(&self) -> Result<(Scientific, Scientific), Error> {
+    let (root, remainder) = self.inner.isqrt()?;
+    Ok((Scientific { inner: root }, Scientific { inner: remainder }))
+  }
+
+  /// Test if the value is the square of an integer.
+  #[inline]
+  #[must_use]
+  pub fn is_perfect_square(&self) -> bool {
+    self.inner.is_perfect_square()
+  }
+
+  /// Calculate the integer `n`-th root, truncated towards zero.
+  ///
+  /// Negative values are only allowed for odd `n` (the root of `-9` with `n = 3` is `-2`).
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::NumberIsNotAnInteger`] if the value has decimals,
+  /// [`Error::NumberIsNegative`] if the value is negative and `n` is even
+  /// and [`Error::DivisionByZero`] if `n` is zero.
+  #[inline]
+  pub fn iroot(&self, n: usize) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.iroot(n)?,
+    })
+  }
+
+  /// Test if the value is a perfect power, i.e. `base^exponent` with an exponent of at least 2.
+  ///
+  /// Returns the smallest base (in absolute value) with the largest exponent (`64` results in `(2, 6)`,
+  /// `-27` in `(-3, 3)`), or `None` for all other values (including those with decimals).
+  /// 0, 1 and -1 are powers with any exponent, they are returned with the smallest exponent
+  /// (2 for 0 and 1, 3 for -1).
+  #[inline]
+  #[must_use]
+  pub fn is_perfect_power(&self) -> Option<(Scientific, usize)> {
+    self
+      .inner
+      .is_perfect_power()
+      .map(|(inner, exponent)| (Scientific { inner }, exponent))
+  }
+
+  /// Calculate the factorial `n!`.
+  ///
+  /// The factors are multiplied in a product tree, which is much faster than multiplying them
+  /// one by one for large `n`.
+  #[inline]
+  pub fn factorial(n: usize) -> Scientific {
+    Scientific {
+      inner: Sci::factorial(n),
+    }
+  }
+
+  /// Calculate the binomial coefficient `n` choose `k`, which is zero if `k` is larger than `n`.
+  #[inline]
+  pub fn binomial(n: usize, k: usize) -> Scientific {
+    Scientific {
+      inner: Sci::binomial(n, k),
+    }
+  }
+
+  /// Calculate the gamma function, with included rpsp (Rounding to Prepare for Shorter Precision).
+  ///
+  /// Positive integers up to 1000 are calculated with [`factorial`](Scientific::factorial),
+  /// all other values with Spouge's approximation.
+  ///
+  /// As all RPSP functions it calculates one more digit than requested for simpler
+  /// usage of the final rounding.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::DivisionByZero`] for the poles (zero and the negative integers) and
+  /// [`Error::NumberTooLarge`] if the exponent of the result doesn't fit into an `isize`
+  /// (e.g. for `1e20`, use [`ln_gamma_rpsp`](Scientific::ln_gamma_rpsp) for such values).
+  #[inline]
+  pub fn gamma_rpsp(&self, precision: Precision) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.gamma(precision + 1)?,
+    })
+  }
+
+  /// Calculate the natural logarithm of the absolute value of the gamma function,
+  /// with included rpsp (Rounding to Prepare for Shorter Precision).
+  ///
+  /// This is also usable for arguments whose gamma function is too large to be calculated.
+  ///
+  /// As all RPSP functions it calculates one more digit than requested for simpler
+  /// usage of the final rounding.
+  ///
+  /// # Errors
+  ///
+  /// Will return [`Error::DivisionByZero`] for the poles (zero and the negative integers).
+  #[inline]
+  pub fn ln_gamma_rpsp(&self, precision: Precision) -> Result<Scientific, Error> {
+    Ok(Scientific {
+      inner: self.inner.ln_gamma(precision + 1)?,
+    })
+  }
+
+  /// Calculate the sum of all values with a single rounding at the end.
+  ///
+  /// The result is correctly rounded, regardless of the order and the magnitudes of the values.
+  /// Only the digits down to a few digits below the rounding position are added exactly,
+  /// smaller digits are only looked at if the sum cancels out or is close to a rounding boundary.
+  pub fn sum_rounded<'a>(
+    iter: impl IntoIterator<Item = &'a Scientific>,
+    precision: Precision,
+    rounding: Rounding,
+  ) -> Scientific {
+    Scientific {
+      inner: Sci::sum_rounded(
+        iter.into_iter().map(|value| value.inner.clone()).collect(),
+        precision,
+        RoundingMode::Rounding(rounding),
+      ),
+    }
+  }
+
+  /// Calculate the dot product of `a` and `b` with a single rounding at the end.
+  ///
+  /// The products are calculated exactly and summed like in [`sum_rounded`](Scientific::sum_rounded),
+  /// so the result is correctly rounded.
+  ///
+  /// # Panics
+  ///
+  /// Will panic if `a` and `b` have different lengths.
+  pub fn dot_rounded(
+    a: &[Scientific],
+    b: &[Scientific],
+    precision: Precision,
+    rounding: Rounding,
+  ) -> Scientific {
+    assert_eq!(a.len(), b.len(), "dot_rounded: the lengths differ");
+    Scientific {
+      inner: Sci::sum_rounded(
+        a.iter()
+          .zip(b.iter())
+          .map(|(a, b)| a.inner.mul(&b.inner))
+          .collect(),
+        precision,
+        RoundingMode::Rounding(rounding),
+      ),
+    }
+  }
+
+  /// Calculate `self * b + c` with included rpsp (Rounding to Prepare for Shorter Precision).
+  ///
+  /// There is only one rounding step, the product is not calculated if it is too small to
+  /// influence the result.
+  ///
+  /// As all RPSP functions it calculates one more digit than requested for simpler
+  /// usage of the final rounding.
+  #[inline]
+  pub fn mul_add_rpsp(&self, b: &Scientific, c: &Scientific, precision: Precision) -> Scientific {
+    Scientific {
+      inner: self
+        .inner
+        .mul_add(&b.inner, &c.inner, precision + 1, RoundingMode::RPSP(RPSP)),
+    }
+  }
+
+  /// Calculate `self * b + c`, correctly rounded with only one rounding step.
+  #[inline]
+  pub fn mul_add_round(
+    &self,
+    b: &Scientific,
+    c: &Scientific,
+    precision: Precision,
+    rounding: Rounding,
+  ) -> Scientific {
+    Scientific {
+      inner: self.inner.mul_add(
+        &b.inner,
+        &c.inner,
+        precision,
+        RoundingMode::Rounding(rounding),
+      ),
+    }
+  }
+
   /// Calculate division with included rpsp (Rounding to Prepare for Shorter Precision)
   ///
   /// Use rpsp (Rounding to Prepare for Shorter Precision) only during internal calculations and
@@ -342,8 +1123,9 @@ impl Add for &Scientific {
 }
 
 impl AddAssign<&Self> for Scientific {
+  #[inline]
   fn add_assign(&mut self, rhs: &Self) {
-    *self = self.add(rhs);
+    self.inner.add_assign(&rhs.inner, 0);
   }
 }
 
@@ -360,7 +1142,7 @@ impl Mul for &Scientific {
 
 impl MulAssign<&Self> for Scientific {
   fn mul_assign(&mut self, rhs: &Self) {
-    *self = self.mul(rhs);
+    *self = &*self * rhs;
   }
 }
 
@@ -423,8 +1205,144 @@ impl Sub for &Scientific {
 }
 
 impl SubAssign<&Self> for Scientific {
+  #[inline]
   fn sub_assign(&mut self, rhs: &Self) {
-    *self = self.sub(rhs);
+    let mut rhs = rhs.inner.clone();
+    rhs.neg_assign();
+    self.inner.add_assign(&rhs, 0);
+  }
+}
+
+impl Div for &Scientific {
+  type Output = Scientific;
+
+  /// Calculate division with the precision [`Precision::F64`], truncating
+  /// (use [`div_truncate`](Scientific::div_truncate) or [`div_rpsp`](Scientific::div_rpsp) for other precisions).
+  ///
+  /// # Panics
+  ///
+  /// Will panic if `rhs` is zero.
+  #[inline]
+  fn div(self, rhs: Self) -> Self::Output {
+    self
+      .div_truncate(rhs, Precision::F64)
+      .expect("attempt to divide by zero")
+  }
+}
+
+impl Rem for &Scientific {
+  type Output = Scientific;
+
+  /// Calculate the remainder of the integer division (the result has the sign of `self`).
+  ///
+  /// # Panics
+  ///
+  /// Will panic if `rhs` is zero.
+  #[inline]
+  fn rem(self, rhs: Self) -> Self::Output {
+    self
+      .div_rem(rhs)
+      .expect("attempt to calculate the remainder with a divisor of zero")
+      .1
+  }
+}
+
+macro_rules! op_by_value {
+  ($trait:ident, $fn:ident) => {
+    impl $trait for Scientific {
+      type Output = Scientific;
+
+      #[inline]
+      fn $fn(self, rhs: Self) -> Self::Output {
+        (&self).$fn(&rhs)
+      }
+    }
+  };
+}
+
+op_by_value!(Mul, mul);
+op_by_value!(Div, div);
+op_by_value!(Rem, rem);
+
+impl Add for Scientific {
+  type Output = Scientific;
+
+  /// Add by value, the result is written into the mantissa of `self` or `rhs`
+  /// if it is not shared and large enough.
+  #[inline]
+  fn add(mut self, mut rhs: Self) -> Self::Output {
+    if self.inner.try_add_assign(&rhs.inner) {
+      self
+    } else if rhs.inner.try_add_assign(&self.inner) {
+      rhs
+    } else {
+      &self + &rhs
+    }
+  }
+}
+
+impl Sub for Scientific {
+  type Output = Scientific;
+
+  /// Subtract by value, the result is written into the mantissa of `self` or `rhs`
+  /// if it is not shared and large enough.
+  #[inline]
+  fn sub(mut self, mut rhs: Self) -> Self::Output {
+    rhs.inner.neg_assign();
+    if self.inner.try_add_assign(&rhs.inner) {
+      self
+    } else if rhs.inner.try_add_assign(&self.inner) {
+      rhs
+    } else {
+      Scientific {
+        inner: self.inner.add(&rhs.inner),
+      }
+    }
+  }
+}
+
+impl Neg for Scientific {
+  type Output = Scientific;
+
+  #[inline]
+  fn neg(mut self) -> Self::Output {
+    self.inner.neg_assign();
+    self
+  }
+}
+
+impl<'a> Sum<&'a Scientific> for Scientific {
+  /// The exact sum, see [`sum_rounded`](Scientific::sum_rounded) for a rounded one.
+  #[inline]
+  fn sum<I: Iterator<Item = &'a Scientific>>(iter: I) -> Self {
+    let mut acc = Accumulator::new();
+    acc.extend(iter);
+    acc.into_sum()
+  }
+}
+
+impl Sum for Scientific {
+  #[inline]
+  fn sum<I: Iterator<Item = Scientific>>(iter: I) -> Self {
+    let mut acc = Accumulator::new();
+    for value in iter {
+      acc.add(&value);
+    }
+    acc.into_sum()
+  }
+}
+
+impl<'a> Product<&'a Scientific> for Scientific {
+  #[inline]
+  fn product<I: Iterator<Item = &'a Scientific>>(iter: I) -> Self {
+    iter.fold(Scientific::ONE, |product, value| &product * value)
+  }
+}
+
+impl Product for Scientific {
+  #[inline]
+  fn product<I: Iterator<Item = Scientific>>(iter: I) -> Self {
+    iter.fold(Scientific::ONE, |product, value| &product * &value)
   }
 }
 
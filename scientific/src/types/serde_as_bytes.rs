@@ -0,0 +1,72 @@
+//! De-/Serialize a [`Scientific`](struct@crate::Scientific) always in the compressed binary format.
+//!
+//! Use with `#[serde(with = "scientific::serde::as_bytes")]`, the variant for
+//! `Option<Scientific>` is [`option`](crate::serde::as_bytes::option).
+//!
+//! This is the same as the default for non human-readable formats (see
+//! [`Scientific::to_bytes`](crate::Scientific::to_bytes)), but is also used for all other formats.
+
+use crate::types::scientific::Scientific;
+use crate::types::serde_de::deserialize_bytes;
+use crate::types::serde_option::{deserialize_option, serialize_option, SerdeWith};
+use crate::types::serde_ser::serialize_bytes;
+use serde::{Deserializer, Serializer};
+
+/// Serialize a [`Scientific`](struct@crate::Scientific) in the compressed binary format.
+pub fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serialize_bytes(value, serializer)
+}
+
+/// Deserialize a [`Scientific`](struct@crate::Scientific) from the compressed binary format.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserialize_bytes(deserializer)
+}
+
+struct AsBytes;
+
+impl SerdeWith for AsBytes {
+  fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize(value, serializer)
+  }
+
+  fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize(deserializer)
+  }
+}
+
+/// De-/Serialize an `Option<Scientific>` always in the compressed binary format.
+///
+/// Use with `#[serde(with = "scientific::serde::as_bytes::option")]`.
+pub mod option {
+  use super::AsBytes;
+  use super::{deserialize_option, serialize_option, Scientific};
+  use serde::{Deserializer, Serializer};
+
+  /// Serialize an `Option<Scientific>` in the optional compressed binary format.
+  pub fn serialize<S>(value: &Option<Scientific>, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize_option::<AsBytes, S>(value.as_ref(), serializer)
+  }
+
+  /// Deserialize an `Option<Scientific>` from the optional compressed binary format.
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Scientific>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize_option::<AsBytes, D>(deserializer)
+  }
+}
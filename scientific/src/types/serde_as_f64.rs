@@ -0,0 +1,72 @@
+//! De-/Serialize a [`Scientific`](struct@crate::Scientific) as a [`f64`].
+//!
+//! Use with `#[serde(with = "scientific::serde::as_f64")]`, the variant for
+//! `Option<Scientific>` is [`option`](crate::serde::as_f64::option).
+//!
+//! Please be aware that this conversion is lossy, an [`f64`] has only a precision of
+//! about 15.9 digits.
+
+use crate::types::scientific::Scientific;
+use crate::types::serde_option::{deserialize_option, serialize_option, SerdeWith};
+use alloc::string::ToString;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize a [`Scientific`](struct@crate::Scientific) as a [`f64`].
+pub fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serializer.serialize_f64(f64::from(value))
+}
+
+/// Deserialize a [`Scientific`](struct@crate::Scientific) from a [`f64`].
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Scientific::try_from(f64::deserialize(deserializer)?)
+    .map_err(|err| serde::de::Error::custom(err.to_string()))
+}
+
+struct AsF64;
+
+impl SerdeWith for AsF64 {
+  fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize(value, serializer)
+  }
+
+  fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize(deserializer)
+  }
+}
+
+/// De-/Serialize an `Option<Scientific>` as an optional [`f64`].
+///
+/// Use with `#[serde(with = "scientific::serde::as_f64::option")]`.
+pub mod option {
+  use super::AsF64;
+  use super::{deserialize_option, serialize_option, Scientific};
+  use serde::{Deserializer, Serializer};
+
+  /// Serialize an `Option<Scientific>` as an optional [`f64`].
+  pub fn serialize<S>(value: &Option<Scientific>, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize_option::<AsF64, S>(value.as_ref(), serializer)
+  }
+
+  /// Deserialize an `Option<Scientific>` from an optional [`f64`].
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Scientific>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize_option::<AsF64, D>(deserializer)
+  }
+}
@@ -0,0 +1,90 @@
+//! De-/Serialize a [`Scientific`](struct@crate::Scientific) as a string with a fixed number of decimals.
+//!
+//! Use with `#[serde(with = "scientific::serde::as_fixed::Decimals::<2>")]`, the variant for
+//! `Option<Scientific>` is [`option::Decimals`](crate::serde::as_fixed::option::Decimals).
+//!
+//! The value is rounded with [`RoundHalfAwayFromZero`](crate::Rounding::RoundHalfAwayFromZero) and
+//! always written without an exponent, e.g. `1.5` with `Decimals::<2>` is written as `"1.50"`
+//! and with `Decimals::<-2>` as `"0"`.
+//!
+//! When deserializing any number (as a string) is accepted and not rounded.
+
+use crate::types::precision::Precision;
+use crate::types::rounding::Rounding;
+use crate::types::scientific::Scientific;
+use crate::types::serde_de::deserialize_string;
+use crate::types::serde_option::{deserialize_option, serialize_option, SerdeWith};
+use crate::types::serde_ser::fixed_to_string;
+use serde::{Deserializer, Serializer};
+
+/// De-/Serialize a [`Scientific`](struct@crate::Scientific) as a string with `DECIMALS` decimals.
+///
+/// See the [module-level documentation](crate::serde::as_fixed) for more details.
+pub struct Decimals<const DECIMALS: isize>;
+
+impl<const DECIMALS: isize> Decimals<DECIMALS> {
+  /// Serialize a [`Scientific`](struct@crate::Scientific) as a string with `DECIMALS` decimals.
+  pub fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let value = value.round(
+      Precision::Decimals(DECIMALS),
+      Rounding::RoundHalfAwayFromZero,
+    );
+    serializer.serialize_str(&fixed_to_string(&value.inner, DECIMALS))
+  }
+
+  /// Deserialize a [`Scientific`](struct@crate::Scientific) from a string.
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize_string(deserializer)
+  }
+}
+
+impl<const DECIMALS: isize> SerdeWith for Decimals<DECIMALS> {
+  fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    Decimals::<DECIMALS>::serialize(value, serializer)
+  }
+
+  fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    Decimals::<DECIMALS>::deserialize(deserializer)
+  }
+}
+
+/// De-/Serialize an `Option<Scientific>` as an optional string with a fixed number of decimals.
+///
+/// Use with `#[serde(with = "scientific::serde::as_fixed::option::Decimals::<2>")]`.
+pub mod option {
+  use super::{deserialize_option, serialize_option, Scientific};
+  use serde::{Deserializer, Serializer};
+
+  /// De-/Serialize an `Option<Scientific>` as an optional string with `DECIMALS` decimals.
+  pub struct Decimals<const DECIMALS: isize>;
+
+  impl<const DECIMALS: isize> Decimals<DECIMALS> {
+    /// Serialize an `Option<Scientific>` as an optional string with `DECIMALS` decimals.
+    pub fn serialize<S>(value: &Option<Scientific>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+      S: Serializer,
+    {
+      serialize_option::<super::Decimals<DECIMALS>, S>(value.as_ref(), serializer)
+    }
+
+    /// Deserialize an `Option<Scientific>` from an optional string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Scientific>, D::Error>
+    where
+      D: Deserializer<'de>,
+    {
+      deserialize_option::<super::Decimals<DECIMALS>, D>(deserializer)
+    }
+  }
+}
@@ -0,0 +1,86 @@
+//! De-/Serialize a [`Scientific`](struct@crate::Scientific) as a JSON number.
+//!
+//! Use with `#[serde(with = "scientific::serde::as_number")]`, the variant for
+//! `Option<Scientific>` is [`option`](crate::serde::as_number::option).
+//!
+//! **This is unsupported outside of `serde_json` with the feature `arbitrary_precision`.** The number
+//! is written with the private token which `serde_json` uses for arbitrary precision numbers, all other
+//! human-readable serializers (including `serde_json` without the feature) will write a map like
+//! `{"$serde_json::private::Number":"1.5"}` instead of a number, use
+//! [`as_string`](crate::serde::as_string) for them.
+//! Non human-readable formats use the same compressed format as without this adapter.
+//!
+//! When deserializing numbers and strings are accepted.
+
+use crate::types::scientific::Scientific;
+use crate::types::serde_de::NUMBER_TOKEN;
+use crate::types::serde_option::{deserialize_option, serialize_option, SerdeWith};
+use crate::types::serde_ser::display_to_string;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialize a [`Scientific`](struct@crate::Scientific) as a JSON number.
+pub fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  if serializer.is_human_readable() {
+    let mut s = serializer.serialize_struct(NUMBER_TOKEN, 1)?;
+    s.serialize_field(NUMBER_TOKEN, &display_to_string(&value.inner))?;
+    s.end()
+  } else {
+    value.serialize(serializer)
+  }
+}
+
+/// Deserialize a [`Scientific`](struct@crate::Scientific) from a JSON number or string.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  // the default does already accept numbers
+  Scientific::deserialize(deserializer)
+}
+
+struct AsNumber;
+
+impl SerdeWith for AsNumber {
+  fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize(value, serializer)
+  }
+
+  fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize(deserializer)
+  }
+}
+
+/// De-/Serialize an `Option<Scientific>` as a JSON number.
+///
+/// Use with `#[serde(with = "scientific::serde::as_number::option")]`.
+pub mod option {
+  use super::AsNumber;
+  use super::{deserialize_option, serialize_option, Scientific};
+  use serde::{Deserializer, Serializer};
+
+  /// Serialize an `Option<Scientific>` as an optional JSON number.
+  pub fn serialize<S>(value: &Option<Scientific>, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize_option::<AsNumber, S>(value.as_ref(), serializer)
+  }
+
+  /// Deserialize an `Option<Scientific>` from an optional JSON number or string.
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Scientific>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize_option::<AsNumber, D>(deserializer)
+  }
+}
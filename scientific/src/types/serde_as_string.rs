@@ -0,0 +1,72 @@
+//! De-/Serialize a [`Scientific`](struct@crate::Scientific) always as a string.
+//!
+//! Use with `#[serde(with = "scientific::serde::as_string")]`, the variant for
+//! `Option<Scientific>` is [`option`](crate::serde::as_string::option).
+//!
+//! This is the same as the default for human-readable formats, but is also used for
+//! all other formats.
+
+use crate::types::scientific::Scientific;
+use crate::types::serde_de::deserialize_string;
+use crate::types::serde_option::{deserialize_option, serialize_option, SerdeWith};
+use crate::types::serde_ser::serialize_string;
+use serde::{Deserializer, Serializer};
+
+/// Serialize a [`Scientific`](struct@crate::Scientific) as a string.
+pub fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serialize_string(value, serializer)
+}
+
+/// Deserialize a [`Scientific`](struct@crate::Scientific) from a string.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserialize_string(deserializer)
+}
+
+struct AsString;
+
+impl SerdeWith for AsString {
+  fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize(value, serializer)
+  }
+
+  fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize(deserializer)
+  }
+}
+
+/// De-/Serialize an `Option<Scientific>` always as a string.
+///
+/// Use with `#[serde(with = "scientific::serde::as_string::option")]`.
+pub mod option {
+  use super::AsString;
+  use super::{deserialize_option, serialize_option, Scientific};
+  use serde::{Deserializer, Serializer};
+
+  /// Serialize an `Option<Scientific>` as an optional string.
+  pub fn serialize<S>(value: &Option<Scientific>, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize_option::<AsString, S>(value.as_ref(), serializer)
+  }
+
+  /// Deserialize an `Option<Scientific>` from an optional string.
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Scientific>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize_option::<AsString, D>(deserializer)
+  }
+}
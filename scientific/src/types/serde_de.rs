@@ -2,7 +2,7 @@ use crate::types::scientific::Scientific;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt::Formatter;
-use serde::de::{SeqAccess, Visitor};
+use serde::de::{MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
@@ -11,48 +11,171 @@ impl<'de> Deserialize<'de> for Scientific {
   where
     D: Deserializer<'de>,
   {
-    struct SciVisitor;
-    impl<'de> Visitor<'de> for SciVisitor {
-      type Value = Scientific;
+    if deserializer.is_human_readable() {
+      deserialize_number::<false, D>(deserializer)
+    } else {
+      deserialize_bytes(deserializer)
+    }
+  }
+}
 
-      fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("Scientific")
-      }
+pub(crate) fn deserialize_string<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Scientific::from_string(String::deserialize(deserializer)?)
+    .map_err(|err| serde::de::Error::custom(err.to_string()))
+}
 
-      fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
-      where
-        E: serde::de::Error,
-      {
-        Scientific::from_bytes(v).map_err(|bse| serde::de::Error::custom(bse.to_string()))
-      }
+// Accepts strings and all kinds of numbers, floats are either converted exactly or
+// with the shortest representation.
+pub(crate) fn deserialize_number<'de, const EXACT_FLOAT: bool, D>(
+  deserializer: D,
+) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserializer.deserialize_any(NumberVisitor::<EXACT_FLOAT>)
+}
 
-      fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
-      where
-        D: Deserializer<'de>,
-      {
-        deserializer.deserialize_bytes(SciVisitor)
-      }
+pub(crate) fn deserialize_bytes<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserializer.deserialize_newtype_struct("Scientific", SciVisitor)
+}
 
-      fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-      where
-        A: SeqAccess<'de>,
-      {
-        let mut buf = match seq.size_hint() {
-          None => Vec::new(),
-          Some(l) => Vec::with_capacity(l),
-        };
-        while let Some(e) = seq.next_element()? {
-          buf.push(e);
-        }
-        Scientific::from_bytes(&buf).map_err(|bse| serde::de::Error::custom(bse.to_string()))
-      }
+struct SciVisitor;
+
+impl<'de> Visitor<'de> for SciVisitor {
+  type Value = Scientific;
+
+  fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+    formatter.write_str("Scientific")
+  }
+
+  fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Scientific::from_bytes(v).map_err(|bse| serde::de::Error::custom(bse.to_string()))
+  }
+
+  fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_bytes(SciVisitor)
+  }
+
+  fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+  where
+    A: SeqAccess<'de>,
+  {
+    let mut buf = match seq.size_hint() {
+      None => Vec::new(),
+      Some(l) => Vec::with_capacity(l),
+    };
+    while let Some(e) = seq.next_element()? {
+      buf.push(e);
     }
+    Scientific::from_bytes(&buf).map_err(|bse| serde::de::Error::custom(bse.to_string()))
+  }
+}
 
-    if deserializer.is_human_readable() {
-      Scientific::from_string(String::deserialize(deserializer)?)
-        .map_err(|err| serde::de::Error::custom(err.to_string()))
+// This is the magic name `serde_json` uses to pass a number with arbitrary precision.
+pub(crate) const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+struct NumberVisitor<const EXACT_FLOAT: bool>;
+
+impl<'de, const EXACT_FLOAT: bool> Visitor<'de> for NumberVisitor<EXACT_FLOAT> {
+  type Value = Scientific;
+
+  fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+    formatter.write_str("a number or a string containing a number")
+  }
+
+  fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(Scientific::from(v))
+  }
+
+  fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(Scientific::from(v))
+  }
+
+  fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(Scientific::from(v))
+  }
+
+  fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Ok(Scientific::from(v))
+  }
+
+  fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    if EXACT_FLOAT {
+      Scientific::from_f64_exact(f64::from(v))
     } else {
-      deserializer.deserialize_newtype_struct("Scientific", SciVisitor)
+      Scientific::try_from(v)
+    }
+    .map_err(|err| serde::de::Error::custom(err.to_string()))
+  }
+
+  fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    if EXACT_FLOAT {
+      Scientific::from_f64_exact(v)
+    } else {
+      Scientific::try_from(v)
+    }
+    .map_err(|err| serde::de::Error::custom(err.to_string()))
+  }
+
+  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    self.visit_string(v.to_string())
+  }
+
+  fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+  where
+    E: serde::de::Error,
+  {
+    Scientific::from_string(v).map_err(|err| serde::de::Error::custom(err.to_string()))
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+  where
+    A: MapAccess<'de>,
+  {
+    // a number from `serde_json` with the feature `arbitrary_precision`
+    match map.next_key::<String>()? {
+      Some(key) if key == NUMBER_TOKEN => {
+        let value = Scientific::from_string(map.next_value::<String>()?)
+          .map_err(|err| serde::de::Error::custom(err.to_string()))?;
+        if map.next_key::<String>()?.is_some() {
+          return Err(serde::de::Error::custom("unexpected key after number"));
+        }
+        Ok(value)
+      }
+      _ => Err(serde::de::Error::custom("expected a number")),
     }
   }
 }
@@ -0,0 +1,78 @@
+//! Deserialize a [`Scientific`](struct@crate::Scientific) with the exact value of floats.
+//!
+//! Use with `#[serde(with = "scientific::serde::exact_float")]`, the variant for
+//! `Option<Scientific>` is [`option`](crate::serde::exact_float::option).
+//!
+//! By default a float (e.g. from a JSON number without `arbitrary_precision` or a YAML float) is
+//! converted with the shortest representation, with this adapter the exact binary value is used
+//! (see [`Scientific::from_f64_exact`](crate::Scientific::from_f64_exact)).
+//!
+//! Serialization is the same as the default.
+
+use crate::types::scientific::Scientific;
+use crate::types::serde_de::deserialize_number;
+use crate::types::serde_option::{deserialize_option, serialize_option, SerdeWith};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialize a [`Scientific`](struct@crate::Scientific), the same as the default.
+pub fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  value.serialize(serializer)
+}
+
+/// Deserialize a [`Scientific`](struct@crate::Scientific), floats are converted exactly.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  if deserializer.is_human_readable() {
+    deserialize_number::<true, D>(deserializer)
+  } else {
+    Scientific::deserialize(deserializer)
+  }
+}
+
+struct ExactFloat;
+
+impl SerdeWith for ExactFloat {
+  fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize(value, serializer)
+  }
+
+  fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize(deserializer)
+  }
+}
+
+/// De-/Serialize an `Option<Scientific>`, floats are converted exactly.
+///
+/// Use with `#[serde(with = "scientific::serde::exact_float::option")]`.
+pub mod option {
+  use super::ExactFloat;
+  use super::{deserialize_option, serialize_option, Scientific};
+  use serde::{Deserializer, Serializer};
+
+  /// Serialize an `Option<Scientific>`, the same as the default.
+  pub fn serialize<S>(value: &Option<Scientific>, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serialize_option::<ExactFloat, S>(value.as_ref(), serializer)
+  }
+
+  /// Deserialize an `Option<Scientific>`, floats are converted exactly.
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Scientific>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserialize_option::<ExactFloat, D>(deserializer)
+  }
+}
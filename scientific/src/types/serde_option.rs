@@ -0,0 +1,59 @@
+use crate::types::scientific::Scientific;
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// An adapter which is used with `#[serde(with = "...")]`, this allows to implement the
+// variant for `Option<Scientific>` only once.
+pub(crate) trait SerdeWith {
+  fn serialize<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer;
+
+  fn deserialize<'de, D>(deserializer: D) -> Result<Scientific, D::Error>
+  where
+    D: Deserializer<'de>;
+}
+
+struct SerializeWith<'a, W>(&'a Scientific, PhantomData<W>);
+
+impl<W: SerdeWith> Serialize for SerializeWith<'_, W> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    W::serialize(self.0, serializer)
+  }
+}
+
+struct DeserializeWith<W>(Scientific, PhantomData<W>);
+
+impl<'de, W: SerdeWith> Deserialize<'de> for DeserializeWith<W> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    Ok(DeserializeWith(W::deserialize(deserializer)?, PhantomData))
+  }
+}
+
+pub(crate) fn serialize_option<W, S>(
+  value: Option<&Scientific>,
+  serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+  W: SerdeWith,
+  S: Serializer,
+{
+  match value {
+    None => serializer.serialize_none(),
+    Some(value) => serializer.serialize_some(&SerializeWith::<W>(value, PhantomData)),
+  }
+}
+
+pub(crate) fn deserialize_option<'de, W, D>(deserializer: D) -> Result<Option<Scientific>, D::Error>
+where
+  W: SerdeWith,
+  D: Deserializer<'de>,
+{
+  Ok(Option::<DeserializeWith<W>>::deserialize(deserializer)?.map(|value| value.0))
+}
@@ -11,27 +11,41 @@ impl Serialize for Scientific {
     S: Serializer,
   {
     if serializer.is_human_readable() {
-      serializer.serialize_str(&display_to_string(&self.inner))
+      serialize_string(self, serializer)
     } else {
-      serializer.serialize_newtype_struct("Scientific", {
-        struct SW(Vec<u8>);
+      serialize_bytes(self, serializer)
+    }
+  }
+}
 
-        impl Serialize for SW {
-          fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-          where
-            S: Serializer,
-          {
-            serializer.serialize_bytes(&self.0)
-          }
-        }
+pub(crate) fn serialize_string<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serializer.serialize_str(&display_to_string(&value.inner))
+}
+
+pub(crate) fn serialize_bytes<S>(value: &Scientific, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serializer.serialize_newtype_struct("Scientific", {
+    struct SW(Vec<u8>);
 
-        &SW(self.to_bytes())
-      })
+    impl Serialize for SW {
+      fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+      where
+        S: Serializer,
+      {
+        serializer.serialize_bytes(&self.0)
+      }
     }
-  }
+
+    &SW(value.to_bytes())
+  })
 }
 
-fn display_to_string(value: &Sci) -> String {
+pub(crate) fn display_to_string(value: &Sci) -> String {
   if value.is_zero() {
     return "0".to_string();
   }
@@ -41,3 +55,12 @@ fn display_to_string(value: &Sci) -> String {
     .expect("writing to String should not fail");
   result
 }
+
+pub(crate) fn fixed_to_string(value: &Sci, decimals: isize) -> String {
+  let mut result =
+    String::with_capacity(value.exponent0().max(1) as usize + decimals.max(0) as usize + 2);
+  value
+    .display_fixed(&mut result, decimals)
+    .expect("writing to String should not fail");
+  result
+}
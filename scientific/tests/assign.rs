@@ -12,7 +12,7 @@ fn assign() {
   ] {
     let mut a = sci.clone();
     a.neg_assign();
-    let b = sci.neg();
+    let b = (&sci).neg();
     assert_eq!(a, b, "neg");
 
     let mut a = sci.clone();
@@ -0,0 +1,43 @@
+#![cfg(feature = "bigdecimal")]
+
+use bigdecimal::BigDecimal;
+use scientific::Scientific;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[test]
+fn bigdecimal() {
+  for value in [
+    "0",
+    "1.5",
+    "-0.001",
+    "123456789012345678901234567890.123456789012345678901234567890",
+    "-1e1000",
+    "1e-1000",
+  ] {
+    let sci = Scientific::from_str(value).unwrap();
+    let big = BigDecimal::from_str(value).unwrap();
+    assert_eq!(Scientific::try_from(&big), Ok(sci.clone()), "{value}");
+    assert_eq!(BigDecimal::from(&sci), big, "{value}");
+  }
+
+  // not normalized
+  let big = BigDecimal::new(1200.into(), 5);
+  assert_eq!(
+    Scientific::try_from(big),
+    Ok(Scientific::from_str("0.012").unwrap())
+  );
+}
+
+#[test]
+fn bigdecimal_random() {
+  for _ in 0..1000 {
+    let big = BigDecimal::new(
+      (rand::random::<i128>() >> (rand::random::<u32>() % 128)).into(),
+      i64::from(rand::random::<i16>()),
+    );
+    let sci = Scientific::try_from(&big).unwrap();
+    assert_eq!(sci, Scientific::from_str(&big.to_string()).unwrap());
+    assert_eq!(BigDecimal::from(&sci), big);
+  }
+}
@@ -0,0 +1,197 @@
+use scientific::{ConversionError, Rounding, Scientific};
+use std::str::FromStr;
+
+fn bytes(high: u64, low: u64) -> [u8; 16] {
+  ((u128::from(high) << 64) | u128::from(low)).to_le_bytes()
+}
+
+#[test]
+fn bson_decimal128() {
+  for (sci, high, low) in [
+    ("0", 0x3040_0000_0000_0000, 0),
+    ("1", 0x3040_0000_0000_0000, 1),
+    ("-1", 0xb040_0000_0000_0000, 1),
+    ("-0.1", 0xb03e_0000_0000_0000, 1),
+    ("1e-6176", 0, 1),
+    ("1234e5", 0x304a_0000_0000_0000, 1234),
+    (
+      "9.999999999999999999999999999999999e6144",
+      0x5fff_ed09_bead_87c0,
+      0x378d_8e63_ffff_ffff,
+    ),
+  ] {
+    let sci = Scientific::from_str(sci).unwrap();
+    let bytes = bytes(high, low);
+    assert_eq!(
+      sci.to_bson_decimal128(Rounding::RoundHalfToEven),
+      Ok(bytes),
+      "{sci} to bson"
+    );
+    assert_eq!(
+      Scientific::from_bson_decimal128(bytes),
+      Ok(sci.clone()),
+      "{sci} from bson"
+    );
+  }
+}
+
+#[test]
+fn bson_decimal128_rounding() {
+  let digits = "1234567890123456789012345678901234";
+  for (sci, rounding, result) in [
+    (
+      format!("{digits}5"),
+      Rounding::RoundHalfToEven,
+      format!("{digits}0"),
+    ),
+    (
+      format!("{digits}5"),
+      Rounding::RoundHalfAwayFromZero,
+      "1234567890123456789012345678901235e1".to_string(),
+    ),
+    (
+      format!("-{digits}9e-10"),
+      Rounding::RoundTowardsZero,
+      format!("-{digits}e-9"),
+    ),
+    // subnormal
+    (
+      "1.5e-6176".to_string(),
+      Rounding::RoundHalfUp,
+      "2e-6176".to_string(),
+    ),
+    (
+      "1.23e-6175".to_string(),
+      Rounding::RoundHalfUp,
+      "1.2e-6175".to_string(),
+    ),
+    (
+      "4e-6177".to_string(),
+      Rounding::RoundHalfUp,
+      "0".to_string(),
+    ),
+    ("-4e-6177".to_string(), Rounding::RoundUp, "0".to_string()),
+    // clamped
+    (
+      "1e6144".to_string(),
+      Rounding::RoundHalfUp,
+      "1e6144".to_string(),
+    ),
+    (
+      "9.9999999999999999999999999999999999e6144".to_string(),
+      Rounding::RoundDown,
+      "9.999999999999999999999999999999999e6144".to_string(),
+    ),
+  ] {
+    let sci = Scientific::from_str(&sci).unwrap();
+    let result = Scientific::from_str(&result).unwrap();
+    let bytes = sci.to_bson_decimal128(rounding).unwrap();
+    assert_eq!(
+      Scientific::from_bson_decimal128(bytes),
+      Ok(result.clone()),
+      "{sci} to bson ({rounding:?})"
+    );
+  }
+
+  let bytes = Scientific::from_str("1e6144")
+    .unwrap()
+    .to_bson_decimal128(Rounding::RoundHalfUp)
+    .unwrap();
+  assert_eq!(u128::from_le_bytes(bytes) >> 113, 6111 + 6176);
+
+  for sci in ["1e6145", "-9.9999999999999999999999999999999999e6144"] {
+    assert_eq!(
+      Scientific::from_str(sci)
+        .unwrap()
+        .to_bson_decimal128(Rounding::RoundHalfUp),
+      Err(ConversionError::NumberTooLarge),
+      "{sci}"
+    );
+  }
+}
+
+#[test]
+fn bson_decimal128_special() {
+  for (high, low) in [
+    (0x7800_0000_0000_0000, 0),
+    (0xf800_0000_0000_0000, 0),
+    (0x7c00_0000_0000_0000, 0),
+    (0x7e00_0000_0000_0000, 12),
+  ] {
+    assert_eq!(
+      Scientific::from_bson_decimal128(bytes(high, low)),
+      Err(ConversionError::FloatIsNotFinite)
+    );
+  }
+
+  // non-canonical values are zero
+  for (high, low) in [
+    (0x6c10_0000_0000_0000, 0),
+    (0x3041_ed09_bead_87c0, 0x378d_8e64_0000_0000),
+    (0x8000_0000_0000_0000, 0),
+  ] {
+    assert_eq!(
+      Scientific::from_bson_decimal128(bytes(high, low)),
+      Ok(Scientific::ZERO)
+    );
+  }
+}
+
+#[test]
+fn bson_decimal128_random() {
+  for _ in 0..10000 {
+    let coefficient = rand::random::<u128>() % 10_u128.pow(34);
+    let exponent = (rand::random::<u16>() % 12288) as isize - 6176;
+    let negative = rand::random::<bool>();
+    let sci = Scientific::from_raw_parts(
+      negative,
+      coefficient
+        .to_string()
+        .into_bytes()
+        .iter()
+        .map(|c| c - b'0')
+        .collect(),
+      exponent,
+    )
+    .unwrap();
+    let bytes = sci.to_bson_decimal128(Rounding::RoundHalfToEven).unwrap();
+    assert_eq!(Scientific::from_bson_decimal128(bytes), Ok(sci));
+  }
+}
+
+#[test]
+fn google_decimal() {
+  for (string, sci) in [
+    ("0", "0"),
+    ("+1.5", "1.5"),
+    ("-.5", "-0.5"),
+    ("2.", "2"),
+    ("1E3", "1e3"),
+    ("1.5e+3", "1500"),
+    ("-1.5e-3", "-0.0015"),
+    ("007.100", "7.1"),
+  ] {
+    assert_eq!(
+      Scientific::from_google_decimal(string),
+      Ok(Scientific::from_str(sci).unwrap()),
+      "{string}"
+    );
+  }
+
+  for string in [
+    "", "+", ".", "e5", "1e", "1.5.3", "1e1.5", " 1", "1 ", "NaN", "inf", "0x10", "1_000",
+  ] {
+    assert_eq!(
+      Scientific::from_google_decimal(string),
+      Err(ConversionError::ParseError),
+      "{string}"
+    );
+  }
+
+  for sci in ["0", "-1.5", "1234567", "0.00123", "1.5e30", "-2e-30"] {
+    let sci = Scientific::from_str(sci).unwrap();
+    let string = sci.to_google_decimal();
+    assert!(!string.starts_with('+') && !string.ends_with('.') && !string.contains('E'));
+    assert_eq!(Scientific::from_google_decimal(&string), Ok(sci));
+  }
+}
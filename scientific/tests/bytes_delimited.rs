@@ -0,0 +1,73 @@
+use scientific::{ConversionError, Scientific};
+use std::str::FromStr;
+
+#[test]
+fn bytes_delimited() {
+  let numbers = [
+    Scientific::from_str("0").unwrap(),
+    Scientific::from_str("-12340").unwrap(),
+    Scientific::from_str("1.234e-100").unwrap(),
+    Scientific::from_str("123.4e999").unwrap(),
+    Scientific::from_str(&"123456789".repeat(100)).unwrap(),
+  ];
+
+  let mut stream = Vec::new();
+  for number in &numbers {
+    number.encode_into(&mut stream);
+  }
+  assert_eq!(&stream[0..5], &[0, 3, 0x81, 0x1e, 0xd0]);
+  // 900 digits need 375 bytes (plus one for the exponent): 0x178 = 0b10_1111000
+  let pos = stream.len() - 378;
+  assert_eq!(&stream[pos..pos + 2], &[0xf8, 0x02]);
+
+  let mut pos = 0;
+  for number in &numbers {
+    let (decoded, len) = Scientific::decode_prefix(&stream[pos..]).unwrap();
+    assert_eq!(&decoded, number);
+    pos += len;
+  }
+  assert_eq!(pos, stream.len());
+
+  #[cfg(feature = "std")]
+  {
+    let mut written = Vec::new();
+    for number in &numbers {
+      number.write_to(&mut written).unwrap();
+    }
+    assert_eq!(written, stream);
+    let mut reader = written.as_slice();
+    for number in &numbers {
+      assert_eq!(&Scientific::read_from(&mut reader).unwrap(), number);
+    }
+    assert!(reader.is_empty());
+    assert_eq!(
+      Scientific::read_from(&mut reader).map_err(|e| e.kind()),
+      Err(std::io::ErrorKind::UnexpectedEof)
+    );
+    assert_eq!(
+      Scientific::read_from(&mut [2, 0x00].as_slice()).map_err(|e| e.kind()),
+      Err(std::io::ErrorKind::UnexpectedEof)
+    );
+    assert_eq!(
+      Scientific::read_from(&mut [1, 0x00].as_slice()).map_err(|e| e.kind()),
+      Err(std::io::ErrorKind::InvalidData)
+    );
+  }
+
+  for bytes in [
+    vec![],
+    vec![0x80],       // length incomplete
+    vec![0x80, 0x00], // length not shortest
+    vec![
+      0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+    ], // length overflow
+    vec![0x02, 0x00], // data incomplete
+    vec![0x01, 0x00], // invalid data
+  ] {
+    assert_eq!(
+      Scientific::decode_prefix(&bytes).map(|_| ()),
+      Err(ConversionError::ParseError),
+      "{bytes:?}"
+    );
+  }
+}
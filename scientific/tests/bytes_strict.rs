@@ -0,0 +1,88 @@
+use scientific::{ConversionError, Scientific};
+use std::str::FromStr;
+
+#[test]
+fn bytes_strict() {
+  for number in [
+    "0",
+    "-12340",
+    "1.234",
+    "1e-64",
+    "1e59",
+    "1e-65",
+    "1e60",
+    "1e-128",
+    "1e127",
+    "1e-129",
+    "1e128",
+    "1e-32768",
+    "1e32767",
+    "1e-32769",
+    "1e32768",
+    "1e-2147483648",
+    "1e2147483647",
+    "1e-2147483649",
+    "1e2147483648",
+  ] {
+    let number = Scientific::from_str(number).unwrap();
+    assert_eq!(
+      Scientific::from_bytes_strict(&number.to_bytes()),
+      Ok(number.clone()),
+      "{number}"
+    );
+  }
+
+  for bytes in [
+    vec![0x3c, 0x00, 0x10],                   // exponent 0 in 1 extra byte
+    vec![0x3c, 0x3b, 0x10],                   // exponent 59 in 1 extra byte
+    vec![0x3d, 0x00, 0x10, 0x10],             // exponent 16 in 2 extra bytes
+    vec![0x3e, 0x00, 0x00, 0x00, 0x10, 0x10], // exponent 16 in 4 extra bytes
+    vec![0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x10], // exponent 16 in 8 extra bytes
+  ] {
+    assert!(Scientific::from_bytes(&bytes).is_ok(), "{bytes:?}");
+    assert_eq!(
+      Scientific::from_bytes_strict(&bytes),
+      Err(ConversionError::ParseError),
+      "{bytes:?}"
+    );
+  }
+}
+
+#[test]
+fn bytes_strict_random() {
+  let mut prefixes = vec![vec![]];
+  prefixes.extend((0..=255).map(|p| vec![p]));
+  for prefix in [0x3c, 0x3d, 0x3e, 0x3f, 0xbc, 0xbd, 0xbe, 0xbf] {
+    prefixes.push(vec![prefix, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    prefixes.push(vec![prefix, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+  }
+
+  let mut accepted = 0;
+  for prefix in prefixes {
+    for _ in 0..200 {
+      let len = rand::random::<u8>() % 8;
+      let mut bytes = prefix.clone();
+      bytes.extend((0..len).map(|_| rand::random::<u8>()));
+
+      match Scientific::from_bytes_strict(&bytes) {
+        Ok(number) => {
+          // the canonical representation is unique
+          accepted += 1;
+          assert_eq!(number.to_bytes(), bytes);
+          assert_eq!(Scientific::from_bytes(&bytes), Ok(number));
+        }
+        Err(_) => {
+          if let Ok(number) = Scientific::from_bytes(&bytes) {
+            // this is another representation of the same value
+            assert_ne!(number.to_bytes(), bytes);
+            assert_eq!(
+              Scientific::from_bytes_strict(&number.to_bytes()),
+              Ok(number)
+            );
+          }
+        }
+      }
+    }
+  }
+  assert!(accepted > 0);
+}
@@ -0,0 +1,127 @@
+use ciborium::tag::Required;
+use scientific::{ConversionError, Scientific};
+use std::str::FromStr;
+
+#[test]
+fn cbor_decimal() {
+  for (sci, bytes) in [
+    ("0", vec![0xc4, 0x82, 0x00, 0x00]),
+    // example from RFC 8949
+    ("273.15", vec![0xc4, 0x82, 0x21, 0x19, 0x6a, 0xb3]),
+    ("-1.5e30", vec![0xc4, 0x82, 0x18, 0x1d, 0x2e]),
+    ("-1", vec![0xc4, 0x82, 0x00, 0x20]),
+    (
+      "18446744073709551616",
+      vec![0xc4, 0x82, 0x00, 0xc2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0],
+    ),
+    (
+      "-18446744073709551616",
+      vec![
+        0xc4, 0x82, 0x00, 0x3b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+      ],
+    ),
+    (
+      "-18446744073709551617",
+      vec![0xc4, 0x82, 0x00, 0xc3, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0],
+    ),
+  ] {
+    let sci = Scientific::from_str(sci).unwrap();
+    assert_eq!(sci.to_cbor_decimal(), bytes, "{sci} to cbor");
+    assert_eq!(
+      Scientific::from_cbor_decimal(&bytes),
+      Ok(sci.clone()),
+      "{sci} from cbor"
+    );
+  }
+}
+
+#[test]
+fn cbor_decimal_non_canonical() {
+  for (bytes, sci) in [
+    // mantissa with trailing zeroes
+    (vec![0xc4, 0x82, 0x21, 0x19, 0x0b, 0xb8], "30"),
+    // long encoding of the exponent
+    (vec![0xc4, 0x82, 0x39, 0x00, 0x01, 0x03], "0.03"),
+    // bignum with leading zeroes
+    (vec![0xc4, 0x82, 0x00, 0xc2, 0x43, 0x00, 0x00, 0x07], "7"),
+    (vec![0xc4, 0x82, 0x05, 0xc3, 0x40], "-1e5"),
+    // zero with exponent
+    (vec![0xc4, 0x82, 0x25, 0x00], "0"),
+  ] {
+    assert_eq!(
+      Scientific::from_cbor_decimal(&bytes),
+      Ok(Scientific::from_str(sci).unwrap()),
+      "{sci} from cbor"
+    );
+  }
+}
+
+#[test]
+fn cbor_decimal_error() {
+  for bytes in [
+    &[][..],
+    // no tag
+    &[0x82, 0x00, 0x00],
+    // wrong tag
+    &[0xc5, 0x82, 0x00, 0x00],
+    // wrong array length
+    &[0xc4, 0x83, 0x00, 0x00, 0x00],
+    // exponent as bignum
+    &[0xc4, 0x82, 0xc2, 0x41, 0x01, 0x00],
+    // mantissa as float
+    &[0xc4, 0x82, 0x00, 0xf9, 0x3c, 0x00],
+    // truncated
+    &[0xc4, 0x82, 0x00, 0x19, 0x01],
+    &[0xc4, 0x82, 0x00, 0xc2, 0x43, 0x00],
+    // indefinite length
+    &[0xc4, 0x9f, 0x00, 0x00, 0xff],
+    // trailing data
+    &[0xc4, 0x82, 0x00, 0x00, 0x00],
+  ] {
+    assert_eq!(
+      Scientific::from_cbor_decimal(bytes),
+      Err(ConversionError::ParseError),
+      "{bytes:?}"
+    );
+  }
+
+  #[cfg(target_pointer_width = "64")]
+  assert_eq!(
+    Scientific::from_cbor_decimal(&[
+      0xc4, 0x82, 0x1b, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01
+    ]),
+    Err(ConversionError::ExponentTooLargeForThisPlatform),
+  );
+}
+
+#[test]
+fn cbor_decimal_ciborium() {
+  type DecimalFraction = Required<(i32, i128), 4>;
+
+  for _ in 0..10000 {
+    let exponent = rand::random::<i32>();
+    let mantissa = rand::random::<i128>() >> (rand::random::<u32>() % 128);
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&Required::<_, 4>((exponent, mantissa)), &mut bytes).unwrap();
+
+    let sci = Scientific::from_cbor_decimal(&bytes).unwrap();
+    let expected = Scientific::from_str(&format!("{mantissa}e{exponent}")).unwrap();
+    assert_eq!(sci, expected);
+    if mantissa % 10 != 0 {
+      assert_eq!(sci.to_cbor_decimal(), bytes);
+    }
+
+    let Required((e, m)): DecimalFraction =
+      ciborium::from_reader(sci.to_cbor_decimal().as_slice()).unwrap();
+    assert_eq!(Scientific::from_str(&format!("{m}e{e}")).unwrap(), expected);
+  }
+}
+
+#[test]
+fn cbor_decimal_large() {
+  let sci = Scientific::from_str(&format!("-{}e-7", "123456789".repeat(100))).unwrap();
+  assert_eq!(
+    Scientific::from_cbor_decimal(&sci.to_cbor_decimal()),
+    Ok(sci)
+  );
+}
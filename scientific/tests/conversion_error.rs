@@ -89,6 +89,7 @@ fn conversion_error() {
     vec![0x00],             // len==0
     vec![0x00, 0x18, 0xc0], // *data==0: value "099" (3 digits), "0" (1 digit), 0b00 padding
     vec![0x00, 0x7d, 0x00], // trailing zeroes: value "500" (3 digits), "0" (1 digit), 0b00 padding
+    vec![0x00, 0xa0],       // value "10" (1 digit), "0" (1 digit)
   ] {
     assert_eq!(
       Scientific::from_bytes(&bytes),
@@ -118,4 +119,8 @@ fn conversion_error() {
     &ConversionError::ExponentTooLargeForThisPlatform.to_string(),
     "Exponent is too large for this platform"
   );
+  assert_eq!(
+    &ConversionError::PrecisionTooLarge.to_string(),
+    "Precision too large"
+  );
 }
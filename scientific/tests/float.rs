@@ -79,3 +79,29 @@ where
     }
   }
 }
+
+#[test]
+fn float_exact() {
+  for (f, s) in [
+    (0.0, "0"),
+    (-0.0, "0"),
+    (1.0, "1"),
+    (-12.5, "-12.5"),
+    (1e23, "99999999999999991611392"),
+    (0.1, "0.1000000000000000055511151231257827021181583404541015625"),
+    (f64::MAX, "179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558632766878171540458953514382464234321326889464182768467546703537516986049910576551282076245490090389328944075868508455133942304583236903222948165808559332123348274797826204144723168738177180919299881250404026184124858368"),
+    (f64::MIN_POSITIVE, "2.225073858507201383090232717332404064219215980462331830553327416887204434813918195854283159012511020564067339731035811005152434161553460108856012385377718821130777993532002330479610147442583636071921565046942503734208375250806650616658158948720491179968591639648500635908770118304874799780887753749949451580451605050915399856582470818645113537935804992115981085766051992433352114352390148795699609591288891602992641511063466313393663477586513029371762047325631781485664350872122828637642044846811407613911477062801689853244110024161447421618567166150540154285084716752901903161322778896729707373123334086988983175067838846926092773977972858659654941091369095406136467568702398678315290680984617210924625396728515625e-308"),
+    (5e-324, "4.940656458412465441765687928682213723650598026143247644255856825006755072702087518652998363616359923797965646954457177309266567103559397963987747960107818781263007131903114045278458171678489821036887186360569987307230500063874091535649843873124733972731696151400317153853980741262385655911710266585566867681870395603106249319452715914924553293054565444011274801297099995419319894090804165633245247571478690147267801593552386115501348035264934720193790268107107491703332226844753335720832431936092382893458368060106011506169809753078342277318329247904982524730776375927247874656084778203734469699533647017972677717585125660551199131504891101451037862738167250955837389733598993664809941164205702637090279242767544565229087538682506419718265533447265625e-324"),
+  ] {
+    assert_eq!(
+      Scientific::from_f64_exact(f),
+      Ok(Scientific::from_str(s).unwrap()),
+      "{f}"
+    );
+    if f.abs() > 1e-300 {
+      // the conversion to f64 does not support very small numbers
+      assert_eq!(f64::from(&Scientific::from_f64_exact(f).unwrap()), f);
+    }
+  }
+  assert!(Scientific::from_f64_exact(f64::NAN).is_err());
+}
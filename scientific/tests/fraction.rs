@@ -0,0 +1,117 @@
+use crate::sci_common::s;
+use scientific::Scientific;
+
+mod sci_common;
+
+fn divides(divisor: u8, value: &Scientific) -> bool {
+  value
+    .div_rem(&Scientific::from(divisor))
+    .unwrap()
+    .1
+    .is_zero()
+}
+
+#[test]
+fn to_fraction() {
+  for (sci, numerator, denominator) in [
+    ("0", "0", "1"),
+    ("0.125", "1", "8"),
+    ("-0.125", "-1", "8"),
+    ("12e3", "12000", "1"),
+    ("0.6", "3", "5"),
+    ("1.25", "5", "4"),
+    ("0.1234", "617", "5000"),
+    ("1e-30", "1", "1e30"),
+  ] {
+    assert_eq!(
+      s(sci).to_fraction(),
+      (s(numerator), s(denominator)),
+      "{sci}"
+    );
+  }
+}
+
+#[test]
+fn to_fraction_random() {
+  for _ in 0..1000 {
+    let sci = &Scientific::from(rand::random::<i64>()) >> (rand::random::<u8>() % 30).into();
+    let (numerator, denominator) = sci.to_fraction();
+    assert!(denominator.is_sign_positive());
+    assert_eq!(&sci * &denominator, numerator, "{sci}");
+    // the denominator only has the prime factors 2 and 5
+    assert!(
+      !(divides(2, &numerator) && divides(2, &denominator)),
+      "{sci}"
+    );
+    assert!(
+      !(divides(5, &numerator) && divides(5, &denominator)),
+      "{sci}"
+    );
+  }
+}
+
+#[test]
+fn continued_fraction() {
+  for (sci, max_terms, terms) in [
+    ("0", 10, vec!["0"]),
+    ("5", 10, vec!["5"]),
+    ("0.5", 10, vec!["0", "2"]),
+    ("3.245", 10, vec!["3", "4", "12", "4"]),
+    ("-3.245", 10, vec!["-4", "1", "3", "12", "4"]),
+    ("3.14159265", 4, vec!["3", "7", "15", "1"]),
+    ("1.5", 0, vec![]),
+  ] {
+    assert_eq!(
+      s(sci).continued_fraction(max_terms),
+      terms.iter().map(|t| s(t)).collect::<Vec<_>>(),
+      "{sci}"
+    );
+  }
+}
+
+#[test]
+fn best_rational() {
+  for (sci, max_denominator, numerator, denominator) in [
+    ("3.14159265", "1000", "355", "113"),
+    ("3.14159265", "100", "311", "99"),
+    ("3.14159265", "10", "22", "7"),
+    ("3.14159265", "1", "3", "1"),
+    ("-3.14159265", "1000", "-355", "113"),
+    ("0.125", "1000", "1", "8"),
+    ("0.125", "7", "1", "7"),
+    ("0.3", "2", "1", "2"),
+    ("0.25", "3", "1", "3"),
+    ("0.001", "10", "0", "1"),
+    ("0.75", "0.5", "1", "1"),
+    ("2.71828", "100.9", "193", "71"),
+  ] {
+    assert_eq!(
+      s(sci).best_rational(&s(max_denominator)),
+      (s(numerator), s(denominator)),
+      "{sci} {max_denominator}"
+    );
+  }
+}
+
+#[test]
+fn best_rational_random() {
+  for _ in 0..100 {
+    let sci = &Scientific::from(rand::random::<i32>()) >> 9;
+    let max_denominator = u64::from(rand::random::<u8>()) + 1;
+    let (numerator, denominator) = sci.best_rational(&Scientific::from(max_denominator));
+    let distance = (&(&sci * &denominator) - &numerator).abs();
+    // compare with all fractions with smaller denominators
+    for d in 1..=max_denominator {
+      let d = Scientific::from(d);
+      let n = (&sci * &d).round(
+        scientific::Precision::INTEGER,
+        scientific::Rounding::RoundHalfToEven,
+      );
+      let other = (&(&sci * &d) - &n).abs();
+      assert!(
+        &distance * &d <= &other * &denominator,
+        "{sci} {max_denominator}"
+      );
+    }
+  }
+}
@@ -0,0 +1,209 @@
+use crate::sci_common::s;
+use scientific::{Error, Precision, Rounding, Scientific};
+
+mod sci_common;
+
+fn round(value: &Scientific, precision: Precision) -> Scientific {
+  value.round(precision, Rounding::RoundHalfToEven)
+}
+
+#[test]
+fn factorial() {
+  assert_eq!(Scientific::factorial(0), s("1"));
+  assert_eq!(Scientific::factorial(1), s("1"));
+  assert_eq!(Scientific::factorial(5), s("120"));
+  assert_eq!(Scientific::factorial(20), s("2432902008176640000"));
+  assert_eq!(Scientific::factorial(25), s("15511210043330985984000000"));
+
+  let mut expected = Scientific::ONE;
+  for n in 1..=2000_u32 {
+    expected = &expected * &Scientific::from(n);
+  }
+  assert_eq!(Scientific::factorial(2000), expected);
+}
+
+#[test]
+fn binomial() {
+  assert_eq!(Scientific::binomial(0, 0), s("1"));
+  assert_eq!(Scientific::binomial(10, 0), s("1"));
+  assert_eq!(Scientific::binomial(10, 3), s("120"));
+  assert_eq!(Scientific::binomial(10, 10), s("1"));
+  assert_eq!(Scientific::binomial(5, 7), s("0"));
+  assert_eq!(
+    Scientific::binomial(100, 50),
+    s("100891344545564193334812497256")
+  );
+  assert_eq!(
+    Scientific::binomial(10_000_000_000, 3),
+    s("166666666616666666670000000000")
+  );
+
+  // Pascal's rule, for both the prime factorization and the division
+  for n in [100, 500, 10_000, 1 << 23] {
+    for k in [1, 2, 63, 64, 65, 99] {
+      assert_eq!(
+        Scientific::binomial(n + 1, k + 1),
+        &Scientific::binomial(n, k) + &Scientific::binomial(n, k + 1),
+        "{n} {k}"
+      );
+    }
+  }
+}
+
+#[test]
+fn gamma() {
+  let sqrt_pi = s("1.77245385090551602729816748334114518279754945612238712821380779");
+  for (value, expected) in [
+    ("1", s("1")),
+    ("2", s("1")),
+    ("5", s("24")),
+    ("0.5", sqrt_pi.clone()),
+    (
+      "1.5",
+      s("0.886226925452758013649083741670572591398774728061193564106904"),
+    ),
+    (
+      "2.5",
+      s("1.32934038817913702047362561250585888709816209209179034616036"),
+    ),
+    (
+      "-0.5",
+      s("-3.54490770181103205459633496668229036559509891224477425642762"),
+    ),
+    (
+      "-1.5",
+      s("2.36327180120735470306422331112152691039673260816318283761841"),
+    ),
+    (
+      "-20.3",
+      s("-6.43546620498935120245506946717576512836378214618332507535e-19"),
+    ),
+    (
+      "-3.0000001",
+      s("1666666.457313760127002703833411835999085842884671034200951"),
+    ),
+    (
+      "0.2",
+      s("4.590843711998803053204758275929152003434109998293403017789"),
+    ),
+  ] {
+    for precision in [Precision::Digits(10), Precision::Digits(40)] {
+      assert_eq!(
+        round(&s(value).gamma_rpsp(precision).unwrap(), precision),
+        round(&expected, precision),
+        "{value} {precision:?}"
+      );
+    }
+  }
+
+  // gamma(n) = (n - 1)! also above the limit of the fast path
+  assert_eq!(
+    round(
+      &s("1001").gamma_rpsp(Precision::Digits(30)).unwrap(),
+      Precision::Digits(30)
+    ),
+    round(&Scientific::factorial(1000), Precision::Digits(30))
+  );
+  assert_eq!(
+    round(
+      &s("3.5").gamma_rpsp(Precision::Decimals(20)).unwrap(),
+      Precision::Decimals(20)
+    ),
+    s("3.32335097044784255118")
+  );
+
+  // gamma(x + 1) = x gamma(x)
+  for value in ["0.3", "7.25", "-3.7", "1e-30"] {
+    let precision = Precision::Digits(25);
+    let x = s(value);
+    assert_eq!(
+      round(
+        &(&x + &Scientific::ONE).gamma_rpsp(precision).unwrap(),
+        precision
+      ),
+      round(&(&x * &x.gamma_rpsp(precision).unwrap()), precision),
+      "{value}"
+    );
+  }
+
+  // the exponent of the result doesn't fit into an isize
+  assert_eq!(
+    s("1e20").gamma_rpsp(Precision::Digits(20)),
+    Err(Error::NumberTooLarge)
+  );
+  assert_eq!(
+    s("1e20").gamma_rpsp(Precision::Decimals(20)),
+    Err(Error::NumberTooLarge)
+  );
+  assert_eq!(
+    s("-10000000000000000000.5").gamma_rpsp(Precision::Digits(20)),
+    Err(Error::NumberTooLarge)
+  );
+  assert_eq!(
+    round(
+      &s("-10000000000000000000.5")
+        .gamma_rpsp(Precision::Decimals(20))
+        .unwrap(),
+      Precision::Decimals(20)
+    ),
+    Scientific::ZERO
+  );
+
+  for pole in ["0", "-1", "-100", "-1e19"] {
+    assert_eq!(
+      s(pole).gamma_rpsp(Precision::Digits(10)),
+      Err(Error::DivisionByZero)
+    );
+  }
+}
+
+#[test]
+fn ln_gamma() {
+  for (value, expected, precision) in [
+    ("1", "0", Precision::Digits(10)),
+    ("2", "0", Precision::Digits(10)),
+    (
+      "0.5",
+      "0.572364942924700087071713675676529355823647406457655785757",
+      Precision::Digits(50),
+    ),
+    (
+      "-0.5",
+      "1.26551212348464539648894579713470592389914754081794",
+      Precision::Digits(50),
+    ),
+    (
+      "1001",
+      "5912.12817848816334887813088673",
+      Precision::Digits(30),
+    ),
+    ("1e10", "220258509288.810581471", Precision::Digits(20)),
+    (
+      "1.0000001",
+      "-5.77215582654833525051e-8",
+      Precision::Digits(20),
+    ),
+    ("-100.5", "-364.900968309", Precision::Decimals(9)),
+    (
+      "-100000.5",
+      "-1051303.83363571848834336088",
+      Precision::Digits(27),
+    ),
+    (
+      "-1000000.25",
+      "-12815520.3472324892359077106",
+      Precision::Digits(27),
+    ),
+  ] {
+    assert_eq!(
+      round(&s(value).ln_gamma_rpsp(precision).unwrap(), precision),
+      round(&s(expected), precision),
+      "{value}"
+    );
+  }
+
+  assert_eq!(
+    s("-2").ln_gamma_rpsp(Precision::Digits(10)),
+    Err(Error::DivisionByZero)
+  );
+}
@@ -0,0 +1,102 @@
+use crate::sci_common::s;
+use scientific::{ConversionError, Scientific};
+
+mod sci_common;
+
+// the C99 `%a` representation of a normal f64
+fn hex_float(value: f64) -> String {
+  let bits = value.to_bits();
+  let sign = if bits >> 63 == 1 { "-" } else { "" };
+  let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+  let fraction = bits & ((1 << 52) - 1);
+  format!("{sign}0x1.{fraction:013x}p{exponent:+}")
+}
+
+#[test]
+fn from_hex_float() {
+  for (hex, sci) in [
+    ("0x1.8p+3", "12"),
+    ("0x1.8p3", "12"),
+    ("-0x1p-1", "-0.5"),
+    ("+0X1.4P-2", "0.3125"),
+    ("0x0p+0", "0"),
+    ("0x.8", "0.5"),
+    ("0xA.", "10"),
+    ("0xff", "255"),
+    ("0x1p-1074", "4.940656458412465441765687928682213723650598026143247644255856825006755072702087518652998363616359923797965646954457177309266567103559397963987747960107818781263007131903114045278458171678489821036887186360569987307230500063874091535649843873124733972731696151400317153853980741262385655911710266585566867681870395603106249319452715914924553293054565444011274801297099995419319894090804165633245247571478690147267801593552386115501348035264934720193790268107107491703332226844753335720832431936092382893458368060106011506169809753078342277318329247904982524730776375927247874656084778203734469699533647017972677717585125660551199131504891101451037862738167250955837389733598993664809941164205702637090279242767544565229087538682506419718265533447265625e-324"),
+  ] {
+    assert_eq!(Scientific::from_hex_float(hex), Ok(s(sci)), "{hex}");
+  }
+
+  for hex in [
+    "",
+    "0x",
+    "0xp1",
+    "1.8p3",
+    "0x1.8p",
+    "0x1.8g3",
+    "0x1.8p3.5",
+    "0x1p+-1",
+    "0x-1",
+    " 0x1",
+    "0x1p20001",
+    "0x1p-20001",
+    "0x1p-9223372036854775807",
+  ] {
+    assert_eq!(
+      Scientific::from_hex_float(hex),
+      Err(ConversionError::ParseError),
+      "{hex}"
+    );
+  }
+  // the limits of the exponent
+  assert!(Scientific::from_hex_float("0x1p20000").is_ok());
+  assert!(Scientific::from_hex_float("0x1p-20000").is_ok());
+
+  for hex in ["inf", "-INFINITY", "nan"] {
+    assert_eq!(
+      Scientific::from_hex_float(hex),
+      Err(ConversionError::FloatIsNotFinite),
+      "{hex}"
+    );
+  }
+}
+
+#[test]
+fn to_hex_float() {
+  for (sci, precision, hex) in [
+    ("12", 1, "0x1.8p+3"),
+    ("12", 3, "0x1.800p+3"),
+    ("-0.5", 0, "-0x1p-1"),
+    ("0", 0, "0x0p+0"),
+    ("0", 2, "0x0.00p+0"),
+    ("1", 0, "0x1p+0"),
+    ("0.1", 13, "0x1.999999999999ap-4"),
+    ("0.1", 2, "0x1.9ap-4"),
+    // rounding half to even
+    ("1.03125", 1, "0x1.0p+0"),
+    ("1.09375", 1, "0x1.2p+0"),
+    // rounded up to the next power of two
+    ("1.99", 1, "0x1.0p+1"),
+    ("1e100", 4, "0x1.249bp+332"),
+    ("1e-100", 4, "0x1.bff3p-333"),
+  ] {
+    assert_eq!(s(sci).to_hex_float(precision), hex, "{sci}");
+  }
+}
+
+#[test]
+fn hex_float_random() {
+  for _ in 0..200 {
+    let value = f64::from_bits(rand::random::<u64>());
+    if !value.is_normal() {
+      continue;
+    }
+    let exact = Scientific::from_f64_exact(value).unwrap();
+    let hex = hex_float(value);
+    assert_eq!(Scientific::from_hex_float(&hex), Ok(exact.clone()), "{hex}");
+    let result = exact.to_hex_float(13);
+    assert_eq!(Scientific::from_hex_float(&result), Ok(exact), "{hex}");
+    assert_eq!(result, hex);
+  }
+}
@@ -0,0 +1,135 @@
+use crate::sci_common::s;
+use scientific::{Error, Scientific};
+
+mod sci_common;
+
+#[test]
+fn isqrt() {
+  for (sci, root, remainder) in [
+    ("0", "0", "0"),
+    ("1", "1", "0"),
+    ("2", "1", "1"),
+    ("99", "9", "18"),
+    ("100", "10", "0"),
+    ("1e100", "1e50", "0"),
+    (
+      "1e101",
+      "316227766016837933199889354443271853371955513932521",
+      "431768379534976586326509909294392368176253858584559",
+    ),
+  ] {
+    assert_eq!(s(sci).isqrt(), Ok((s(root), s(remainder))), "{sci}");
+  }
+  assert_eq!(s("-4").isqrt(), Err(Error::NumberIsNegative));
+  assert_eq!(s("4.5").isqrt(), Err(Error::NumberIsNotAnInteger));
+}
+
+#[test]
+fn isqrt_random() {
+  for _ in 0..1000 {
+    let value = rand::random::<u128>() >> (rand::random::<u32>() % 128);
+    let sci = Scientific::from(value);
+    let (root, remainder) = sci.isqrt().unwrap();
+    assert_eq!(&(&root * &root) + &remainder, sci, "{value}");
+    assert!(
+      remainder.is_sign_positive() || remainder.is_zero(),
+      "{value}"
+    );
+    assert!(remainder <= &root + &root, "{value}");
+    assert_eq!(sci.is_perfect_square(), remainder.is_zero());
+  }
+}
+
+#[test]
+fn is_perfect_square() {
+  for (sci, square) in [
+    ("0", true),
+    ("1", true),
+    ("4e10", true),
+    ("4e11", false),
+    ("-4", false),
+    ("0.25", false),
+    ("152415787532388367501905199875019052100", true),
+    ("152415787532388367501905199875019052101", false),
+  ] {
+    assert_eq!(s(sci).is_perfect_square(), square, "{sci}");
+  }
+}
+
+#[test]
+fn iroot() {
+  for (sci, n, root) in [
+    ("0", 5, "0"),
+    ("7", 1, "7"),
+    ("26", 3, "2"),
+    ("27", 3, "3"),
+    ("-26", 3, "-2"),
+    ("-27", 3, "-3"),
+    ("1e30", 3, "1e10"),
+    ("1e30", 7, "19306"),
+    ("1e30", 100, "1"),
+    ("123456789e100", 10, "64439401439"),
+  ] {
+    assert_eq!(s(sci).iroot(n), Ok(s(root)), "{sci} {n}");
+  }
+  assert_eq!(s("-27").iroot(2), Err(Error::NumberIsNegative));
+  assert_eq!(s("27").iroot(0), Err(Error::DivisionByZero));
+  assert_eq!(s("2.7").iroot(3), Err(Error::NumberIsNotAnInteger));
+}
+
+#[test]
+fn iroot_random() {
+  for _ in 0..200 {
+    let value =
+      &Scientific::from(rand::random::<u128>()) * &Scientific::from(rand::random::<u64>());
+    let n = usize::from(rand::random::<u8>() % 20) + 1;
+    let root = value.iroot(n).unwrap();
+    assert!(root.powi(n) <= value, "{value} {n}");
+    assert!((&root + &Scientific::ONE).powi(n) > value, "{value} {n}");
+  }
+}
+
+#[test]
+fn iroot_large() {
+  // the initial value of the iteration is close to the root, also for large values
+  let value = s("123456789012345678901234567890").powi(100) + Scientific::ONE;
+  for n in [2, 3, 7, 50, 999, 1000, 1001, 5_000, 20_000] {
+    let root = value.iroot(n).unwrap();
+    assert!(root.powi(n) <= value, "{n}");
+    assert!((&root + &Scientific::ONE).powi(n) > value, "{n}");
+  }
+  assert_eq!(value.iroot(100), Ok(s("123456789012345678901234567890")));
+}
+
+#[test]
+fn is_perfect_power() {
+  for (sci, result) in [
+    ("0", Some(("0", 2))),
+    ("1", Some(("1", 2))),
+    ("-1", Some(("-1", 3))),
+    ("2", None),
+    ("4", Some(("2", 2))),
+    ("64", Some(("2", 6))),
+    ("-64", Some(("-4", 3))),
+    ("-32", Some(("-2", 5))),
+    ("-4", None),
+    ("72", None),
+    ("1e12", Some(("10", 12))),
+    ("1e13", Some(("10", 13))),
+    ("3.0e1", None),
+    ("0.25", None),
+    ("1853020188851841", Some(("3", 32))),
+    ("1853020188851842", None),
+  ] {
+    assert_eq!(
+      s(sci).is_perfect_power(),
+      result.map(|(base, exponent)| (s(base), exponent)),
+      "{sci}"
+    );
+  }
+  let value = Scientific::from(12345).powi(77);
+  assert_eq!(
+    value.is_perfect_power(),
+    Some((Scientific::from(12345), 77))
+  );
+}
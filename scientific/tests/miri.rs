@@ -1,5 +1,6 @@
 use crate::float_common::test_float;
 use crate::integer_common::test_integer;
+use scientific::{Accumulator, Scientific};
 
 mod float_common;
 mod integer_common;
@@ -9,3 +10,23 @@ fn miri() {
   test_float([-4.33333333, 0., 1., 3., 7.5].into_iter(), &[0, 2], false);
   test_integer([-4, 0, 1, 3, 7].into_iter(), 3, false);
 }
+
+#[test]
+fn miri_in_place() {
+  let values = [-4.25, 0., 1., 999., 0.001, -1000.5].map(|v| Scientific::try_from(v).unwrap());
+  let mut acc = Accumulator::new();
+  let mut value = Scientific::ZERO;
+  let mut expected = Scientific::ZERO;
+  for a in values.iter() {
+    for b in values.iter() {
+      acc.add(a);
+      acc.sub(b);
+      value += a;
+      value -= b;
+      expected = &(&expected + a) - b;
+      assert_eq!(acc.sum(), expected);
+      assert_eq!(value, expected);
+      assert_eq!(a.clone() + b.clone(), a + b);
+    }
+  }
+}
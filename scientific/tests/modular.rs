@@ -0,0 +1,167 @@
+use crate::sci_common::s;
+use scientific::{Error, Scientific};
+
+mod sci_common;
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+  a
+}
+
+#[test]
+fn gcd_lcm() {
+  for (a, b, gcd, lcm) in [
+    ("0", "0", "0", "0"),
+    ("0", "-5", "5", "0"),
+    ("12", "18", "6", "36"),
+    ("-12", "18", "6", "36"),
+    ("-12", "-18", "6", "36"),
+    ("17", "5", "1", "85"),
+    ("1e30", "2e20", "2e20", "1e30"),
+    // the trailing zeros are not expanded
+    (
+      "1e1000000000000",
+      "3e1000000000001",
+      "1e1000000000000",
+      "3e1000000000001",
+    ),
+    ("1e1000000000000", "48", "16", "3e1000000000000"),
+    ("7e1000000000000", "3", "1", "21e1000000000000"),
+    (
+      "340282366920938463463374607431768211456",
+      "18446744073709551616",
+      "18446744073709551616",
+      "340282366920938463463374607431768211456",
+    ),
+  ] {
+    assert_eq!(s(a).gcd(&s(b)), Ok(s(gcd)), "gcd({a}, {b})");
+    assert_eq!(s(b).gcd(&s(a)), Ok(s(gcd)), "gcd({b}, {a})");
+    assert_eq!(s(a).lcm(&s(b)), Ok(s(lcm)), "lcm({a}, {b})");
+  }
+  assert_eq!(s("1.5").gcd(&s("3")), Err(Error::NumberIsNotAnInteger));
+  assert_eq!(s("3").lcm(&s("0.5")), Err(Error::NumberIsNotAnInteger));
+}
+
+#[test]
+fn gcd_random() {
+  for _ in 0..1000 {
+    let common = u128::from(rand::random::<u32>());
+    let a = u128::from(rand::random::<u64>()) * common;
+    let b = u128::from(rand::random::<u64>() >> (rand::random::<u32>() % 64)) * common;
+    assert_eq!(
+      Scientific::from(a).gcd(&Scientific::from(b)),
+      Ok(Scientific::from(gcd_u128(a, b))),
+      "gcd({a}, {b})"
+    );
+  }
+}
+
+#[test]
+fn gcd_large() {
+  // gcd(2^a - 1, 2^b - 1) = 2^gcd(a, b) - 1
+  let two = Scientific::from(2);
+  let a = &two.powi(1001) - &Scientific::ONE;
+  let b = &two.powi(770) - &Scientific::ONE;
+  assert_eq!(a.gcd(&b), Ok(&two.powi(77) - &Scientific::ONE));
+}
+
+#[test]
+fn mod_pow() {
+  for (base, exponent, modulus, result) in [
+    ("4", "13", "497", "445"),
+    ("2", "0", "7", "1"),
+    ("2", "0", "1", "0"),
+    ("0", "0", "5", "1"),
+    ("-2", "3", "5", "2"),
+    ("2", "3", "-5", "-2"),
+    ("10", "3", "-5", "0"),
+    ("3", "1e20", "1000000007", "139421235"),
+    ("2", "1000", "1e30", "429831652624386837205668069376"),
+  ] {
+    assert_eq!(
+      s(base).mod_pow(&s(exponent), &s(modulus)),
+      Ok(s(result)),
+      "{base}^{exponent} mod {modulus}"
+    );
+  }
+  for (base, exponent, modulus, error) in [
+    ("1.5", "2", "5", Error::NumberIsNotAnInteger),
+    ("2", "0.5", "5", Error::NumberIsNotAnInteger),
+    ("2", "2", "5.5", Error::NumberIsNotAnInteger),
+    ("2", "2", "0", Error::DivisionByZero),
+    ("2", "-2", "5", Error::NumberIsNegative),
+    ("2", "1e1000000000000", "5", Error::NumberTooLarge),
+    ("1e1000000000000", "2", "5", Error::NumberTooLarge),
+    ("2", "2", "1e1000000000000", Error::NumberTooLarge),
+  ] {
+    assert_eq!(
+      s(base).mod_pow(&s(exponent), &s(modulus)),
+      Err(error),
+      "{base}^{exponent} mod {modulus}"
+    );
+  }
+}
+
+#[test]
+fn mod_pow_random() {
+  for _ in 0..100 {
+    let base = u128::from(rand::random::<u64>());
+    let exponent = rand::random::<u8>();
+    let modulus = u128::from(rand::random::<u64>() | 1);
+    let mut expected = 1 % modulus;
+    for _ in 0..exponent {
+      expected = expected * (base % modulus) % modulus;
+    }
+    assert_eq!(
+      Scientific::from(base).mod_pow(&Scientific::from(exponent), &Scientific::from(modulus)),
+      Ok(Scientific::from(expected)),
+      "{base}^{exponent} mod {modulus}"
+    );
+  }
+}
+
+#[test]
+fn mod_inverse() {
+  for (value, modulus, result) in [
+    ("3", "11", Some("4")),
+    ("-3", "11", Some("7")),
+    ("3", "-11", Some("-7")),
+    ("10", "17", Some("12")),
+    ("5", "1", Some("0")),
+    ("6", "9", None),
+    ("0", "7", None),
+  ] {
+    assert_eq!(
+      s(value).mod_inverse(&s(modulus)),
+      Ok(result.map(s)),
+      "{value}^-1 mod {modulus}"
+    );
+  }
+  assert_eq!(s("2").mod_inverse(&s("0")), Err(Error::DivisionByZero));
+  assert_eq!(
+    s("3").mod_inverse(&s("1e1000000000000")),
+    Err(Error::NumberTooLarge)
+  );
+  assert_eq!(
+    s("0.5").mod_inverse(&s("3")),
+    Err(Error::NumberIsNotAnInteger)
+  );
+}
+
+#[test]
+fn mod_inverse_random() {
+  let modulus = s("170141183460469231731687303715884105727"); // 2^127 - 1 is prime
+  for _ in 0..100 {
+    let value = Scientific::from(rand::random::<u128>() >> 1);
+    if value.is_zero() {
+      continue;
+    }
+    let inverse = value.mod_inverse(&modulus).unwrap().unwrap();
+    assert_eq!(
+      (&value * &inverse).div_rem(&modulus).unwrap().1,
+      Scientific::ONE
+    );
+  }
+}
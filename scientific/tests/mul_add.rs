@@ -0,0 +1,100 @@
+use crate::sci_common::s;
+use scientific::{Decimals, Digits, Precision, Rounding, Scientific};
+
+mod sci_common;
+
+const ROUNDINGS: [Rounding; 10] = [
+  Rounding::RoundDown,
+  Rounding::RoundUp,
+  Rounding::RoundTowardsZero,
+  Rounding::RoundAwayFromZero,
+  Rounding::RoundHalfDown,
+  Rounding::RoundHalfUp,
+  Rounding::RoundHalfTowardsZero,
+  Rounding::RoundHalfAwayFromZero,
+  Rounding::RoundHalfToEven,
+  Rounding::RoundHalfToOdd,
+];
+
+fn random() -> Scientific {
+  let value = rand::random::<i32>() >> (rand::random::<u32>() % 32);
+  &Scientific::from(value) >> (rand::random::<i8>() % 24) as isize
+}
+
+fn check(a: &Scientific, b: &Scientific, c: &Scientific, precision: Precision) {
+  let exact = &(a * b) + c;
+  for rounding in ROUNDINGS {
+    assert_eq!(
+      a.mul_add_round(b, c, precision, rounding),
+      exact.round(precision, rounding),
+      "{a} * {b} + {c} {precision:?} {rounding:?}"
+    );
+  }
+  assert_eq!(
+    a.mul_add_rpsp(b, c, precision),
+    exact.round_rpsp(precision),
+    "{a} * {b} + {c} {precision:?}"
+  );
+}
+
+#[test]
+fn mul_add() {
+  assert_eq!(
+    s("2").mul_add_round(&s("3"), &s("4"), Digits(5), Rounding::RoundHalfToEven),
+    s("10")
+  );
+  // a single rounding (rounding the product first would give 1.2)
+  assert_eq!(
+    s("0.35").mul_add_round(&s("3"), &s("0.1"), Decimals(1), Rounding::RoundHalfUp),
+    s("1.2")
+  );
+  assert_eq!(
+    s("0.25").mul_add_round(&s("1"), &s("1e-50"), Decimals(1), Rounding::RoundHalfToEven),
+    s("0.3")
+  );
+  assert_eq!(
+    s("1e-50").mul_add_round(&s("-1"), &s("1000"), Digits(3), Rounding::RoundDown),
+    s("999")
+  );
+  assert_eq!(
+    s("1e-50").mul_add_round(&s("1"), &s("1000"), Digits(3), Rounding::RoundUp),
+    s("1010")
+  );
+  assert_eq!(
+    s("1e50").mul_add_round(
+      &s("1e-50"),
+      &s("-1.000001"),
+      Digits(3),
+      Rounding::RoundHalfUp
+    ),
+    s("-0.000001")
+  );
+
+  let zero = Scientific::ZERO;
+  let one = Scientific::ONE;
+  for precision in [Digits(1), Digits(4), Decimals(-2), Decimals(0), Decimals(3)] {
+    check(&zero, &s("1.25"), &s("-7.55"), precision);
+    check(&s("1.25"), &s("-7.55"), &zero, precision);
+    check(&s("1e40"), &s("1e-40"), &s("-1"), precision);
+    check(&s("-9.99"), &one, &s("1e-30"), precision);
+    check(&s("1e-30"), &s("1e-30"), &s("100"), precision);
+    check(&s("1e-30"), &s("-1e-30"), &s("100"), precision);
+    check(&s("1e-30"), &s("1e-30"), &s("-0.01"), precision);
+    check(
+      &s("9.5"),
+      &s("0.1"),
+      &s("-0.95000000000000000001"),
+      precision,
+    );
+  }
+
+  for _ in 0..2000 {
+    let (a, b, c) = (random(), random(), random());
+    let precision = if rand::random::<bool>() {
+      Digits((rand::random::<u8>() % 12) as isize)
+    } else {
+      Decimals((rand::random::<i8>() % 20) as isize)
+    };
+    check(&a, &b, &c, precision);
+  }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "num-bigint")]
+
+use num_bigint::{BigInt, BigUint};
+use scientific::{ConversionError, Scientific};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[test]
+fn bigint() {
+  for value in [
+    "0",
+    "1",
+    "-1",
+    "18446744073709551615",
+    "-18446744073709551616",
+    "123456789012345678901234567890123456789012345678901234567890",
+    "-1000000000000000000000000000000000000000000000000000000000000000000",
+  ] {
+    let sci = Scientific::from_str(value).unwrap();
+    let big = BigInt::from_str(value).unwrap();
+    assert_eq!(Scientific::from(&big), sci, "{value}");
+    assert_eq!(BigInt::try_from(&sci), Ok(big.clone()), "{value}");
+    if !value.starts_with('-') {
+      let big = BigUint::from_str(value).unwrap();
+      assert_eq!(Scientific::from(big.clone()), sci, "{value}");
+      assert_eq!(BigUint::try_from(&sci), Ok(big), "{value}");
+    }
+  }
+}
+
+#[test]
+fn bigint_error() {
+  for (value, error) in [
+    ("1.5", ConversionError::NumberIsNotAnInteger),
+    ("1e-100", ConversionError::NumberIsNotAnInteger),
+    ("1e9999999999", ConversionError::NumberTooLarge),
+    ("1e100000000", ConversionError::NumberTooLarge),
+  ] {
+    let sci = Scientific::from_str(value).unwrap();
+    assert_eq!(BigInt::try_from(&sci), Err(error), "{value}");
+    assert_eq!(BigUint::try_from(&sci), Err(error), "{value}");
+  }
+  assert_eq!(
+    BigInt::try_from(&Scientific::from_str("-1.5").unwrap()),
+    Err(ConversionError::NumberIsNotAnInteger)
+  );
+  assert_eq!(
+    BigUint::try_from(&Scientific::from(-1)),
+    Err(ConversionError::NumberIsNegative)
+  );
+}
+
+#[test]
+fn bigint_random() {
+  for _ in 0..1000 {
+    let value = rand::random::<i128>() >> (rand::random::<u32>() % 128);
+    let big = BigInt::from(value) * BigInt::from(value) * BigInt::from(value);
+    let sci = Scientific::from(&big);
+    assert_eq!(sci, Scientific::from_str(&big.to_string()).unwrap());
+    assert_eq!(BigInt::try_from(&sci), Ok(big));
+  }
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "num-traits")]
+
+use crate::sci_common::s;
+use num_traits::{
+  CheckedDiv, CheckedEuclid, CheckedNeg, CheckedRem, Euclid, FromPrimitive, Num, One, Pow, Signed,
+  ToPrimitive, Zero,
+};
+use scientific::{ConversionError, Scientific};
+
+mod sci_common;
+
+fn sum_of_squares<T: Num + Clone>(values: &[T]) -> T {
+  values
+    .iter()
+    .fold(T::zero(), |acc, v| acc + v.clone() * v.clone())
+}
+
+fn mean<T: Num + FromPrimitive + Clone>(values: &[T]) -> T {
+  values.iter().fold(T::zero(), |acc, v| acc + v.clone()) / T::from_usize(values.len()).unwrap()
+}
+
+#[test]
+fn generic() {
+  let values = [s("1.5"), s("-2"), s("3")];
+  assert_eq!(sum_of_squares(&values), s("15.25"));
+  assert_eq!(mean(&values), s("0.8333333333333333"));
+}
+
+#[test]
+fn identities() {
+  assert!(<Scientific as Zero>::is_zero(&Scientific::zero()));
+  assert!(Scientific::one().is_one());
+  assert!(!s("1.1").is_one());
+  let mut value = s("12");
+  value.set_zero();
+  assert_eq!(value, Scientific::ZERO);
+  assert_eq!(<Scientific as num_traits::ConstOne>::ONE, Scientific::ONE);
+}
+
+#[test]
+fn from_str_radix() {
+  assert_eq!(
+    <Scientific as Num>::from_str_radix("-1.5e3", 10),
+    Ok(s("-1500"))
+  );
+  assert_eq!(<Scientific as Num>::from_str_radix("ff", 16), Ok(s("255")));
+  assert_eq!(
+    <Scientific as Num>::from_str_radix("f.f", 16),
+    Err(ConversionError::ParseError)
+  );
+}
+
+#[test]
+fn signed() {
+  assert_eq!(Signed::abs(&s("-1.5")), s("1.5"));
+  assert_eq!(s("5").abs_sub(&s("3")), s("2"));
+  assert_eq!(s("3").abs_sub(&s("5")), Scientific::ZERO);
+  assert_eq!(s("-0.1").signum(), s("-1"));
+  assert_eq!(s("0").signum(), s("0"));
+  assert_eq!(s("1e-100").signum(), s("1"));
+  assert!(s("2").is_positive() && !s("2").is_negative());
+  assert!(!s("0").is_positive() && !s("0").is_negative());
+}
+
+#[test]
+fn primitive() {
+  assert_eq!(Scientific::from_i64(-5), Some(s("-5")));
+  assert_eq!(
+    Scientific::from_u128(u128::MAX),
+    Some(Scientific::from(u128::MAX))
+  );
+  assert_eq!(Scientific::from_f64(0.25), Some(s("0.25")));
+  assert_eq!(Scientific::from_f64(f64::NAN), None);
+
+  assert_eq!(s("-12.9").to_i64(), Some(-12));
+  assert_eq!(s("300").to_u8(), None);
+  assert_eq!(s("-1").to_u32(), None);
+  assert_eq!(s("-0.5").to_u32(), Some(0));
+  assert_eq!(s("1e30").to_u128(), Some(10_u128.pow(30)));
+  assert_eq!(s("0.5").to_f64(), Some(0.5));
+
+  assert_eq!(
+    <Scientific as num_traits::NumCast>::from(7_u8),
+    Some(s("7"))
+  );
+  assert_eq!(
+    <Scientific as num_traits::NumCast>::from(u128::MAX),
+    Some(Scientific::from(u128::MAX))
+  );
+  assert_eq!(
+    <Scientific as num_traits::NumCast>::from(-0.5_f32),
+    Some(s("-0.5"))
+  );
+  assert_eq!(
+    <Scientific as num_traits::NumCast>::from(f64::INFINITY),
+    None
+  );
+}
+
+#[test]
+fn checked() {
+  assert_eq!(s("1").checked_div(&s("4")), Some(s("0.25")));
+  assert_eq!(s("1").checked_div(&Scientific::ZERO), None);
+  assert_eq!(s("10").checked_rem(&s("4")), Some(s("2")));
+  assert_eq!(s("10").checked_rem(&Scientific::ZERO), None);
+  assert_eq!(s("-7").checked_div_euclid(&s("2")), Some(s("-4")));
+  assert_eq!(s("-7").checked_rem_euclid(&Scientific::ZERO), None);
+  assert_eq!(s("1.5").checked_neg(), Some(s("-1.5")));
+  assert_eq!(Scientific::ZERO.checked_neg(), Some(Scientific::ZERO));
+}
+
+#[test]
+fn euclid() {
+  assert_eq!(Euclid::div_euclid(&s("-7"), &s("2")), s("-4"));
+  assert_eq!(Euclid::rem_euclid(&s("-7"), &s("2")), s("1"));
+  assert_eq!(Euclid::rem_euclid(&s("7.5"), &s("-2")), s("1.5"));
+}
+
+#[test]
+fn pow() {
+  assert_eq!(s("1.5").pow(2_usize), s("2.25"));
+  assert_eq!((&s("-2")).pow(3_usize), s("-8"));
+}
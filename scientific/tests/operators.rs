@@ -0,0 +1,420 @@
+use crate::sci_common::s;
+use scientific::{Accumulator, Decimals, Digits, Error, Precision, Rounding, Scientific};
+
+mod sci_common;
+
+#[test]
+fn by_value() {
+  for _ in 0..1000 {
+    let a = &Scientific::from(rand::random::<i64>()) >> 3;
+    let b = Scientific::from(rand::random::<i32>());
+    assert_eq!(a.clone() + b.clone(), &a + &b);
+    assert_eq!(a.clone() - b.clone(), &a - &b);
+    assert_eq!(a.clone() * b.clone(), &a * &b);
+    assert_eq!(-a.clone(), -&a);
+    if !b.is_zero() {
+      assert_eq!(a.clone() / b.clone(), &a / &b);
+      assert_eq!(a.clone() % b.clone(), &a % &b);
+    }
+  }
+}
+
+#[test]
+fn by_value_in_place() {
+  for _ in 0..1000 {
+    let random = || {
+      let value = rand::random::<i64>() >> (rand::random::<u32>() % 64);
+      let shift = (rand::random::<i8>() % 8) as isize;
+      // a new value each time, which owns its mantissa (which will be reused)
+      move || &Scientific::from(value) >> shift
+    };
+    let (a, b) = (random(), random());
+    let (sum, difference) = (&a() + &b(), &a() - &b());
+    assert_eq!(a() + b(), sum, "{} + {}", a(), b());
+    assert_eq!(a() - b(), difference, "{} - {}", a(), b());
+    assert_eq!(b() - a(), -&difference, "{} - {}", b(), a());
+    assert_eq!(a() - b() + b(), a(), "{} - {} + {}", a(), b(), b());
+
+    // shared mantissas must not be changed
+    let (a, b) = (a(), b());
+    let (a_copy, b_copy) = (a.clone(), b.clone());
+    assert_eq!(a.clone() + b.clone(), sum, "{a} + {b}");
+    assert_eq!(a.clone() - b.clone(), difference, "{a} - {b}");
+    assert_eq!((a, b), (a_copy, b_copy));
+  }
+  assert_eq!(s("999") + s("1"), s("1000"));
+  assert_eq!(s("-999.9") - s("0.1"), s("-1000"));
+  assert_eq!(s("0.001") - s("1000"), s("-999.999"));
+  assert_eq!(s("123") - s("123"), Scientific::ZERO);
+}
+
+#[test]
+fn assign_in_place() {
+  for _ in 0..1000 {
+    let random = || {
+      let value = rand::random::<i64>() >> (rand::random::<u32>() % 64);
+      &Scientific::from(value) >> (rand::random::<i8>() % 8) as isize
+    };
+    let (a, b, c) = (random(), random(), random());
+    let a_copy = a.clone();
+    let mut value = a.clone();
+    value += &b;
+    assert_eq!(value, &a + &b, "{a} + {b}");
+    value -= &c;
+    assert_eq!(value, &(&a + &b) - &c, "{a} + {b} - {c}");
+    value += &c;
+    value -= &b;
+    assert_eq!(value, a, "{a} + {b} - {c} + {c} - {b}");
+    // the shared mantissa must not be changed
+    assert_eq!(a, a_copy);
+  }
+
+  let mut value = s("999");
+  value += &s("1");
+  assert_eq!(value, s("1000"));
+  value -= &s("0.001");
+  assert_eq!(value, s("999.999"));
+  value -= &s("1000");
+  assert_eq!(value, s("-0.001"));
+  value += &s("0.001");
+  assert_eq!(value, Scientific::ZERO);
+}
+
+#[test]
+fn accumulator() {
+  let values = (0..2000)
+    .map(|_| &Scientific::from(rand::random::<i32>()) >> (rand::random::<i8>() % 16) as isize)
+    .collect::<Vec<_>>();
+  let mut expected = Scientific::ZERO;
+  let mut acc = Accumulator::new();
+  for (i, value) in values.iter().enumerate() {
+    expected = &expected + value;
+    acc.add(value);
+    if i % 100 == 0 {
+      assert_eq!(acc.sum(), expected);
+    }
+  }
+  assert_eq!(acc.sum(), expected);
+  for value in values.iter() {
+    acc.sub(value);
+  }
+  assert_eq!(acc.sum(), Scientific::ZERO);
+
+  let mut acc = Accumulator::from(s("0.5"));
+  acc.extend(values.iter());
+  assert_eq!(acc.into_sum(), &expected + &s("0.5"));
+
+  let mut acc = Accumulator::default();
+  acc.add(&s("1e100"));
+  acc.add(&s("1e-100"));
+  acc.sub(&s("1e100"));
+  assert_eq!(acc.into_sum(), s("1e-100"));
+}
+
+#[test]
+fn sum_and_product() {
+  let values = (0..500)
+    .map(|_| &Scientific::from(rand::random::<i32>()) >> (rand::random::<i8>() % 16) as isize)
+    .collect::<Vec<_>>();
+  let sum = values
+    .iter()
+    .fold(Scientific::ZERO, |sum, value| &sum + value);
+  assert_eq!(values.iter().sum::<Scientific>(), sum);
+  assert_eq!(values.clone().into_iter().sum::<Scientific>(), sum);
+  let product = values[..20]
+    .iter()
+    .fold(Scientific::ONE, |product, value| &product * value);
+  assert_eq!(values[..20].iter().product::<Scientific>(), product);
+  assert_eq!(
+    values[..20].iter().cloned().product::<Scientific>(),
+    product
+  );
+
+  assert_eq!([].iter().sum::<Scientific>(), Scientific::ZERO);
+  assert_eq!([].iter().product::<Scientific>(), Scientific::ONE);
+  assert_eq!(
+    [s("1e100"), s("1e-100"), s("-1e100")]
+      .iter()
+      .sum::<Scientific>(),
+    s("1e-100")
+  );
+}
+
+#[test]
+fn sum_and_dot_rounded() {
+  // a single rounding at the end (rounding each step would give 0)
+  let values = [s("0.5"), s("1e-30"), s("-1e-31")];
+  assert_eq!(
+    Scientific::sum_rounded(values.iter(), Precision::INTEGER, Rounding::RoundHalfToEven),
+    s("1")
+  );
+  assert_eq!(
+    Scientific::sum_rounded(
+      values[..1].iter(),
+      Precision::INTEGER,
+      Rounding::RoundHalfToEven
+    ),
+    s("0")
+  );
+  assert_eq!(
+    Scientific::sum_rounded(
+      [s("1e50"), s("0.125"), s("-1e50")].iter(),
+      Digits(2),
+      Rounding::RoundHalfToEven
+    ),
+    s("0.12")
+  );
+  assert_eq!(
+    Scientific::sum_rounded([].iter(), Digits(2), Rounding::RoundUp),
+    Scientific::ZERO
+  );
+
+  let a = [s("1.005"), s("2"), s("1e40")];
+  let b = [s("3"), s("0.0001"), s("-1e-40")];
+  assert_eq!(
+    Scientific::dot_rounded(&a, &b, Decimals(2), Rounding::RoundHalfAwayFromZero),
+    s("2.02")
+  );
+  assert_eq!(
+    Scientific::dot_rounded(&a, &b, Decimals(2), Rounding::RoundDown),
+    s("2.01")
+  );
+  assert_eq!(
+    Scientific::dot_rounded(&[], &[], Decimals(2), Rounding::RoundDown),
+    Scientific::ZERO
+  );
+}
+
+#[test]
+fn sum_rounded_far_apart() {
+  // the digits in between are not calculated
+  let values = [s("1e100000000"), s("1e-100000000"), s("-1e100000000")];
+  assert_eq!(
+    Scientific::sum_rounded(values.iter(), Digits(10), Rounding::RoundHalfToEven),
+    s("1e-100000000")
+  );
+  let values = [s("1e100000000"), s("-1e-100000000")];
+  assert_eq!(
+    Scientific::sum_rounded(values.iter(), Digits(10), Rounding::RoundDown),
+    s("9.999999999e99999999")
+  );
+  assert_eq!(
+    Scientific::sum_rounded(values.iter(), Digits(10), Rounding::RoundHalfUp),
+    s("1e100000000")
+  );
+  // exactly at a tie, only the sign of the tiny values decides
+  let values = [s("1.25"), s("1e-100000000"), s("-2e-100000001")];
+  assert_eq!(
+    Scientific::sum_rounded(values.iter(), Digits(2), Rounding::RoundHalfToEven),
+    s("1.3")
+  );
+  let values = [s("1.25"), s("1e-100000000"), s("-1e-100000000")];
+  assert_eq!(
+    Scientific::sum_rounded(values.iter(), Digits(2), Rounding::RoundHalfToEven),
+    s("1.2")
+  );
+  let a = [s("1e50000000"), s("1e-50000000"), s("1e50000000")];
+  let b = [s("1e50000000"), s("1e-50000000"), s("-1e50000000")];
+  assert_eq!(
+    Scientific::dot_rounded(&a, &b, Digits(10), Rounding::RoundHalfToEven),
+    s("1e-100000000")
+  );
+}
+
+#[test]
+fn sum_rounded_random() {
+  const ROUNDINGS: [Rounding; 10] = [
+    Rounding::RoundDown,
+    Rounding::RoundUp,
+    Rounding::RoundTowardsZero,
+    Rounding::RoundAwayFromZero,
+    Rounding::RoundHalfDown,
+    Rounding::RoundHalfUp,
+    Rounding::RoundHalfTowardsZero,
+    Rounding::RoundHalfAwayFromZero,
+    Rounding::RoundHalfToEven,
+    Rounding::RoundHalfToOdd,
+  ];
+  for _ in 0..2000 {
+    // few different digits and exponents which are far apart, with many cancellations and ties
+    let mut values = Vec::new();
+    for _ in 0..(rand::random::<u8>() % 8) {
+      let value = Scientific::from(rand::random::<i16>() % 8 * 25);
+      let shift = (rand::random::<i8>() % 4) as isize * 20 + (rand::random::<i8>() % 3) as isize;
+      values.push(&value >> shift);
+    }
+    for i in 0..values.len() {
+      if rand::random::<bool>() {
+        values.push(-&values[i]);
+      }
+    }
+    let exact = values.iter().sum::<Scientific>();
+    let precision = if rand::random::<bool>() {
+      Digits((rand::random::<u8>() % 12) as isize)
+    } else {
+      Decimals((rand::random::<i8>() % 50) as isize)
+    };
+    for rounding in ROUNDINGS {
+      assert_eq!(
+        Scientific::sum_rounded(values.iter(), precision, rounding),
+        exact.round(precision, rounding),
+        "{values:?} {precision:?} {rounding:?}"
+      );
+    }
+  }
+}
+
+#[test]
+#[should_panic(expected = "the lengths differ")]
+fn dot_rounded_lengths() {
+  let _ = Scientific::dot_rounded(&[s("1")], &[], Decimals(2), Rounding::RoundDown);
+}
+
+#[test]
+fn primitives() {
+  let a = s("2.5");
+  assert_eq!(&a + 1, s("3.5"));
+  assert_eq!(a.clone() - 3_u8, s("-0.5"));
+  assert_eq!(&a * -2_i64, s("-5"));
+  assert_eq!(&a / 2_usize, s("1.25"));
+  assert_eq!(&a % 2_u128, s("0.5"));
+  assert_eq!(1 + &a, s("3.5"));
+  assert_eq!(3_i16 - a.clone(), s("0.5"));
+  assert_eq!(-2_isize * &a, s("-5"));
+  assert_eq!(5_u32 / a.clone(), s("2"));
+  assert_eq!(7_i8 % &a, s("2"));
+
+  let mut b = a.clone();
+  b += 1;
+  b -= 10_u64;
+  b *= -2;
+  assert_eq!(b, s("13"));
+
+  assert_eq!(b, 13);
+  assert_eq!(13_u8, b);
+  assert_ne!(a, 2);
+  assert!(a > 2);
+  assert!(a < 3_u128);
+  assert!(-3_i32 < a);
+  assert!(i128::MIN < a);
+  assert!(u128::MAX > a);
+  assert!(s("-0.5") < 0);
+  assert_eq!(Scientific::ZERO, 0_usize);
+}
+
+#[test]
+fn floats() {
+  let a = s("2.5");
+  assert_eq!(&a + 0.1, s("2.6"));
+  assert_eq!(a.clone() - 3_f32, s("-0.5"));
+  assert_eq!(&a * -2.0, s("-5"));
+  assert_eq!(&a / 0.5_f64, s("5"));
+  assert_eq!(&a % 2.0, s("0.5"));
+  assert_eq!(0.1 + &a, s("2.6"));
+  assert_eq!(1.5_f32 * a.clone(), s("3.75"));
+
+  let mut b = a.clone();
+  b += 0.5;
+  b *= -2.0_f32;
+  assert_eq!(b, s("-6"));
+
+  assert_eq!(a, 2.5);
+  assert_eq!(2.5_f32, a);
+  assert_ne!(a, f64::NAN);
+  assert!(a > 2.4999);
+  assert!(a < 2.5001_f32);
+  assert!(-1e300 < a);
+  assert!(a < f64::INFINITY && a > f64::NEG_INFINITY);
+  assert!(f32::INFINITY > a);
+  assert_eq!(a.partial_cmp(&f64::NAN), None);
+  assert_eq!(f32::NAN.partial_cmp(&a), None);
+
+  // comparisons use the exact binary value
+  assert_eq!(s("0.100000001490116119384765625"), 0.1_f32);
+  assert_ne!(s("0.1"), 0.1_f32);
+  assert_eq!(
+    s("0.1000000000000000055511151231257827021181583404541015625"),
+    0.1
+  );
+  assert!(s("0.1") < 0.1);
+  assert!(0.1_f32 > s("0.1"));
+}
+
+#[test]
+#[should_panic(expected = "the float is not finite")]
+fn floats_not_finite() {
+  let _ = &Scientific::ONE + f64::NAN;
+}
+
+#[test]
+fn div_rem() {
+  assert_eq!(&s("2") / &s("3"), s("0.6666666666666666"));
+  assert_eq!(&s("-1") / &s("8"), s("-0.125"));
+  assert_eq!(&s("-2e100") / &s("3"), s("-6.666666666666666e99"));
+
+  for (a, b, rem) in [
+    ("7", "3", "1"),
+    ("-7", "3", "-1"),
+    ("7", "-3", "1"),
+    ("7.5", "2", "1.5"),
+    ("1e10", "7", "4"),
+  ] {
+    assert_eq!(&s(a) % &s(b), s(rem), "{a} % {b}");
+  }
+}
+
+#[test]
+fn euclid_and_floor() {
+  for (a, b, div_euclid, rem_euclid, div_floor, mod_floor) in [
+    ("7", "3", "2", "1", "2", "1"),
+    ("-7", "3", "-3", "2", "-3", "2"),
+    ("7", "-3", "-2", "1", "-3", "-2"),
+    ("-7", "-3", "3", "2", "2", "-1"),
+    ("6", "-3", "-2", "0", "-2", "0"),
+    ("-7.5", "2", "-4", "0.5", "-4", "0.5"),
+    ("7.5", "-0.2", "-37", "0.1", "-38", "-0.1"),
+    ("0", "-3", "0", "0", "0", "0"),
+  ] {
+    let (a, b) = (s(a), s(b));
+    assert_eq!(a.div_euclid(&b), Ok(s(div_euclid)), "{a} {b}");
+    assert_eq!(a.rem_euclid(&b), Ok(s(rem_euclid)), "{a} {b}");
+    assert_eq!(a.div_floor(&b), Ok(s(div_floor)), "{a} {b}");
+    assert_eq!(a.mod_floor(&b), Ok(s(mod_floor)), "{a} {b}");
+  }
+
+  for _ in 0..1000 {
+    let a = rand::random::<i64>() >> (rand::random::<u32>() % 64);
+    let b = rand::random::<i32>() >> (rand::random::<u32>() % 32);
+    if b == 0 {
+      continue;
+    }
+    let (sa, sb) = (Scientific::from(a), Scientific::from(b));
+    let b = i64::from(b);
+    assert_eq!(sa.div_euclid(&sb), Ok(Scientific::from(a.div_euclid(b))));
+    assert_eq!(sa.rem_euclid(&sb), Ok(Scientific::from(a.rem_euclid(b))));
+    let mod_floor = (a % b + b) % b;
+    assert_eq!(sa.mod_floor(&sb), Ok(Scientific::from(mod_floor)));
+    assert_eq!(sa.div_floor(&sb), Ok(Scientific::from((a - mod_floor) / b)));
+  }
+
+  assert_eq!(
+    Scientific::ONE.div_euclid(&Scientific::ZERO),
+    Err(Error::DivisionByZero)
+  );
+  assert_eq!(
+    Scientific::ONE.mod_floor(&Scientific::ZERO),
+    Err(Error::DivisionByZero)
+  );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn div_by_zero() {
+  let _ = &Scientific::ONE / &Scientific::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "divisor of zero")]
+fn rem_by_zero() {
+  let _ = &Scientific::ONE % &Scientific::ZERO;
+}
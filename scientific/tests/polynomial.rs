@@ -0,0 +1,191 @@
+use crate::sci_common::s;
+use scientific::{Decimals, Digits, Polynomial, Precision, Rounding, Scientific};
+
+mod sci_common;
+
+fn p(coefficients: &[&str]) -> Polynomial {
+  Polynomial::new(coefficients.iter().map(|c| s(c)).collect())
+}
+
+// the polynomial with the given roots
+fn from_roots(roots: &[Scientific]) -> Polynomial {
+  roots.iter().fold(p(&["1"]), |result, root| {
+    &result * &Polynomial::new(vec![-root, Scientific::ONE])
+  })
+}
+
+#[test]
+fn basic() {
+  let a = p(&["1", "-2.5", "0", "3"]);
+  assert_eq!(a.degree(), Some(3));
+  assert_eq!(p(&["1", "0", "0"]).degree(), Some(0));
+  assert_eq!(p(&["0"]), Polynomial::default());
+  assert!(p(&[]).is_zero());
+  assert_eq!(p(&[]).degree(), None);
+  assert_eq!(a.coefficients(), &[s("1"), s("-2.5"), s("0"), s("3")]);
+
+  assert_eq!(a.eval(&s("2")), s("20"));
+  assert_eq!(a.eval(&s("-0.1")), s("1.247"));
+  assert_eq!(p(&[]).eval(&s("5")), Scientific::ZERO);
+  assert_eq!(a.eval_rpsp(&s("2"), Digits(10)), s("20"));
+  let x = s("1.23456789");
+  assert_eq!(
+    a.eval_rpsp(&x, Digits(20))
+      .round(Digits(15), Rounding::RoundHalfToEven),
+    a.eval(&x).round(Digits(15), Rounding::RoundHalfToEven)
+  );
+
+  assert_eq!(a.derivative(), p(&["-2.5", "0", "9"]));
+  assert_eq!(p(&["7"]).derivative(), Polynomial::default());
+
+  let b = p(&["0.5", "1"]);
+  assert_eq!(&a + &b, p(&["1.5", "-1.5", "0", "3"]));
+  assert_eq!(&b + &a, p(&["1.5", "-1.5", "0", "3"]));
+  assert_eq!(&b - &a, p(&["-0.5", "3.5", "0", "-3"]));
+  assert_eq!(&a - &a, Polynomial::default());
+  assert_eq!(&a * &b, p(&["0.5", "-0.25", "-2.5", "1.5", "3"]));
+  assert_eq!(&a * &p(&[]), Polynomial::default());
+  assert_eq!(-&b, p(&["-0.5", "-1"]));
+  for x in ["0", "1.5", "-7", "1e-10"] {
+    let x = s(x);
+    assert_eq!((&a * &b).eval(&x), &a.eval(&x) * &b.eval(&x));
+  }
+}
+
+#[test]
+fn real_roots() {
+  let round = |precision, rounding| move |x: &str| s(x).round(precision, rounding);
+  let r = round(Digits(20), Rounding::RoundHalfToEven);
+
+  // x^2 - 2
+  assert_eq!(
+    p(&["-2", "0", "1"]).real_roots(Digits(20), Rounding::RoundHalfToEven),
+    [
+      r("-1.41421356237309504880168"),
+      r("1.41421356237309504880168")
+    ]
+  );
+  // x^3 - 2
+  assert_eq!(
+    p(&["-2", "0", "0", "1"]).real_roots(Decimals(30), Rounding::RoundDown),
+    [s("1.259921049894873164767210607278")]
+  );
+  // no real roots
+  assert!(p(&["1", "0", "1"])
+    .real_roots(Digits(5), Rounding::RoundUp)
+    .is_empty());
+  assert!(p(&["5"])
+    .real_roots(Digits(5), Rounding::RoundUp)
+    .is_empty());
+  assert!(p(&[]).real_roots(Digits(5), Rounding::RoundUp).is_empty());
+  // zero and multiple roots
+  assert_eq!(
+    p(&["0", "0", "1"]).real_roots(Digits(5), Rounding::RoundUp),
+    [s("0")]
+  );
+  assert_eq!(
+    from_roots(&[s("-1"), s("0"), s("0"), s("1.5"), s("1.5"), s("1.5")])
+      .real_roots(Digits(5), Rounding::RoundUp),
+    [s("-1"), s("0"), s("1.5")]
+  );
+  // close roots
+  assert_eq!(
+    from_roots(&[s("1"), s("1.000000001"), s("-1e-20")])
+      .real_roots(Digits(12), Rounding::RoundHalfToEven),
+    [s("-1e-20"), s("1"), s("1.000000001")]
+  );
+
+  // roots on the boundaries of the rounding
+  let ties = from_roots(&[s("0.25"), s("-0.35"), s("9.95")]);
+  assert_eq!(
+    ties.real_roots(Decimals(1), Rounding::RoundHalfToEven),
+    [s("-0.4"), s("0.2"), s("10")]
+  );
+  assert_eq!(
+    ties.real_roots(Decimals(1), Rounding::RoundHalfTowardsZero),
+    [s("-0.3"), s("0.2"), s("9.9")]
+  );
+  assert_eq!(
+    ties.real_roots(Digits(2), Rounding::RoundHalfUp),
+    [s("-0.35"), s("0.25"), s("10")]
+  );
+  assert_eq!(
+    ties.real_roots(Digits(1), Rounding::RoundUp),
+    [s("-0.3"), s("0.3"), s("10")]
+  );
+  assert_eq!(
+    ties.real_roots(Digits(1), Rounding::RoundDown),
+    [s("-0.4"), s("0.2"), s("9")]
+  );
+
+  // Wilkinson's polynomial with a small disturbance
+  let roots = (1..=12).map(Scientific::from).collect::<Vec<_>>();
+  let wilkinson = from_roots(&roots);
+  assert_eq!(
+    wilkinson.real_roots(Digits(20), Rounding::RoundHalfToEven),
+    roots
+  );
+  let mut disturbance = vec![Scientific::ZERO; 12];
+  disturbance[11] = s("1e-6");
+  let disturbed = &wilkinson + &Polynomial::new(disturbance);
+  let precision = Digits(10);
+  let disturbed_roots = disturbed.real_roots(precision, Rounding::RoundHalfToEven);
+  assert_eq!(disturbed_roots.len(), 12);
+  assert_eq!(disturbed_roots[0], s("1"));
+  for root in disturbed_roots {
+    // the sign changes within half a unit of the last digit
+    let half = &s("0.5") << (root.exponent0() - 10);
+    let (lo, hi) = (
+      disturbed.eval(&(&root - &half)),
+      disturbed.eval(&(&root + &half)),
+    );
+    assert_ne!(lo.is_sign_negative(), hi.is_sign_negative(), "{root}");
+  }
+}
+
+#[test]
+fn real_roots_random() {
+  for _ in 0..20 {
+    let mut roots = (0..(rand::random::<u8>() % 6 + 1))
+      .map(|_| &Scientific::from(rand::random::<i16>()) >> (rand::random::<u8>() % 8) as isize)
+      .collect::<Vec<_>>();
+    let scale = &Scientific::from(rand::random::<i16>() | 1) >> 3_isize;
+    let polynomial = &from_roots(&roots) * &Polynomial::new(vec![scale]);
+    roots.sort();
+    roots.dedup();
+    for (precision, rounding) in [
+      (Digits(3), Rounding::RoundHalfToEven),
+      (Decimals(2), Rounding::RoundUp),
+      (Decimals(4), Rounding::RoundHalfDown),
+      (Digits(12), Rounding::RoundDown),
+    ] {
+      let expected = roots
+        .iter()
+        .map(|root| root.round(precision, rounding))
+        .collect::<Vec<_>>();
+      assert_eq!(
+        polynomial.real_roots(precision, rounding),
+        expected,
+        "{roots:?} {precision:?} {rounding:?}"
+      );
+    }
+    // irrational roots: x^2 - c
+    let c = roots[0].abs();
+    let sqrt = c.sqrt_truncate(Digits(30)).unwrap();
+    let precision = Precision::Digits(20);
+    let square = Polynomial::new(vec![-&c, Scientific::ZERO, Scientific::ONE]);
+    let found = square.real_roots(precision, Rounding::RoundDown);
+    if c.is_zero() {
+      assert_eq!(found, [Scientific::ZERO]);
+    } else {
+      assert_eq!(
+        found,
+        [
+          -&sqrt.round(precision, Rounding::RoundUp),
+          sqrt.truncate(precision)
+        ],
+        "{c}"
+      );
+    }
+  }
+}
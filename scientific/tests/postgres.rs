@@ -0,0 +1,97 @@
+#![cfg(feature = "postgres")]
+
+use scientific::{ConversionError, Scientific};
+use std::str::FromStr;
+
+#[test]
+fn postgres_numeric() {
+  for (sci, bytes) in [
+    ("0", vec![0, 0, 0, 0, 0, 0, 0, 0]),
+    (
+      "12345.678",
+      vec![0, 3, 0, 1, 0, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1a, 0x7c],
+    ),
+    ("-0.0001", vec![0, 1, 0xff, 0xff, 0x40, 0, 0, 4, 0, 1]),
+    ("0.5", vec![0, 1, 0xff, 0xff, 0, 0, 0, 1, 0x13, 0x88]),
+    ("1e8", vec![0, 1, 0, 2, 0, 0, 0, 0, 0, 1]),
+    ("-2e9", vec![0, 1, 0, 2, 0x40, 0, 0, 0, 0, 20]),
+    (
+      "10000.5",
+      vec![0, 3, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0x13, 0x88],
+    ),
+    (
+      "1.23456789e-10",
+      vec![
+        0, 3, 0xff, 0xfd, 0, 0, 0, 18, 0, 123, 0x11, 0xd7, 0x22, 0xc4,
+      ],
+    ),
+  ] {
+    let sci = Scientific::from_str(sci).unwrap();
+    assert_eq!(
+      sci.to_postgres_numeric(),
+      Ok(bytes.clone()),
+      "{sci} to numeric"
+    );
+    assert_eq!(
+      Scientific::from_postgres_numeric(&bytes),
+      Ok(sci.clone()),
+      "{sci} from numeric"
+    );
+  }
+
+  // as sent by PostgreSQL for `SELECT 1.500::numeric`
+  assert_eq!(
+    Scientific::from_postgres_numeric(&[0, 2, 0, 0, 0, 0, 0, 3, 0, 1, 0x13, 0x88]),
+    Ok(Scientific::from_str("1.5").unwrap())
+  );
+
+  // not representable
+  for sci in ["1e131072", "1e-16384"] {
+    assert_eq!(
+      Scientific::from_str(sci).unwrap().to_postgres_numeric(),
+      Err(ConversionError::NumberTooLarge)
+    );
+  }
+
+  // NaN, Infinity, -Infinity
+  for sign in [0xc0, 0xd0, 0xf0] {
+    assert_eq!(
+      Scientific::from_postgres_numeric(&[0, 0, 0, 0, sign, 0, 0, 0]),
+      Err(ConversionError::FloatIsNotFinite)
+    );
+  }
+
+  for bytes in [
+    vec![],
+    vec![0, 0, 0, 0, 0, 0, 0],
+    vec![0, 0, 0, 0, 0x80, 0, 0, 0],          // invalid sign
+    vec![0, 1, 0, 0, 0, 0, 0, 0],             // missing digit
+    vec![0, 0, 0, 0, 0, 0, 0, 0, 0],          // too many bytes
+    vec![0xff, 0xff, 0, 0, 0, 0, 0, 0],       // negative ndigits
+    vec![0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10], // digit 10000
+  ] {
+    assert_eq!(
+      Scientific::from_postgres_numeric(&bytes),
+      Err(ConversionError::ParseError),
+      "{bytes:?}"
+    );
+  }
+}
+
+#[cfg(feature = "postgres-types")]
+#[test]
+fn postgres_types() {
+  use postgres_types::{FromSql, ToSql, Type};
+
+  let sci = Scientific::from_str("-12345.678").unwrap();
+  let mut buf = bytes::BytesMut::new();
+  sci.to_sql_checked(&Type::NUMERIC, &mut buf).unwrap();
+  assert_eq!(
+    &buf[..],
+    &[0, 3, 0, 1, 0x40, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1a, 0x7c]
+  );
+  assert_eq!(Scientific::from_sql(&Type::NUMERIC, &buf).unwrap(), sci);
+  assert!(sci.to_sql_checked(&Type::INT4, &mut buf).is_err());
+  assert!(<Scientific as FromSql>::accepts(&Type::NUMERIC));
+  assert!(!<Scientific as FromSql>::accepts(&Type::TEXT));
+}
@@ -0,0 +1,113 @@
+use crate::sci_common::s;
+use scientific::{ConversionError, Scientific};
+
+mod sci_common;
+
+fn is_prime_u64(n: u64) -> bool {
+  n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+#[test]
+fn is_probable_prime() {
+  for (sci, prime) in [
+    ("-7", false),
+    ("0", false),
+    ("1", false),
+    ("2", true),
+    ("3", true),
+    ("4", false),
+    ("2.5", false),
+    ("251", true),
+    ("65537", true),
+    ("65535", false),
+    // strong pseudoprime to the bases 2 to 37
+    ("3825123056546413051", false),
+    ("2305843009213693951", true),
+    ("1e20", false),
+    // the trailing zeros are not expanded
+    ("1e1000000000000", false),
+    // 2^89 - 1 and 2^127 - 1
+    ("618970019642690137449562111", true),
+    ("170141183460469231731687303715884105727", true),
+    ("170141183460469231731687303715884105729", false),
+    // product of two 20 digit primes
+    ("1000000000000000005700000000000000000799", false),
+  ] {
+    assert_eq!(s(sci).is_probable_prime(20), prime, "{sci}");
+  }
+}
+
+#[test]
+fn is_probable_prime_random() {
+  for _ in 0..1000 {
+    let n = rand::random::<u64>() % 1_000_000;
+    assert_eq!(
+      Scientific::from(n).is_probable_prime(0),
+      is_prime_u64(n),
+      "{n}"
+    );
+  }
+}
+
+#[test]
+fn next_prime() {
+  for (sci, next) in [
+    ("-5", "2"),
+    ("0", "2"),
+    ("1.9", "2"),
+    ("2", "3"),
+    ("2.5", "3"),
+    ("3", "5"),
+    ("90", "97"),
+    ("1e10", "10000000019"),
+    ("1e30", "1000000000000000000000000000057"),
+  ] {
+    assert_eq!(s(sci).next_prime(), s(next), "{sci}");
+  }
+}
+
+#[test]
+fn factorize() {
+  for (sci, factors) in [
+    ("0", vec![]),
+    ("1", vec![]),
+    ("-1", vec![]),
+    ("-360", vec![("2", 3), ("3", 2), ("5", 1)]),
+    ("1e6", vec![("2", 6), ("5", 6)]),
+    ("65537", vec![("65537", 1)]),
+    ("4295098369", vec![("65537", 2)]),
+    (
+      "600851475143",
+      vec![("71", 1), ("839", 1), ("1471", 1), ("6857", 1)],
+    ),
+    (
+      "1000000016000000063",
+      vec![("1000000007", 1), ("1000000009", 1)],
+    ),
+  ] {
+    assert_eq!(
+      s(sci).factorize(),
+      Ok(factors.iter().map(|(f, c)| (s(f), *c)).collect::<Vec<_>>()),
+      "{sci}"
+    );
+  }
+  assert_eq!(
+    s("1.5").factorize(),
+    Err(ConversionError::NumberIsNotAnInteger)
+  );
+}
+
+#[test]
+fn factorize_random() {
+  for _ in 0..1000 {
+    let n = rand::random::<u64>() >> (rand::random::<u32>() % 32) | 1;
+    let factors = Scientific::from(n).factorize().unwrap();
+    let mut product = Scientific::ONE;
+    for (factor, count) in &factors {
+      assert!(factor.is_probable_prime(20), "{n}: {factor}");
+      product = &product * &factor.powi(*count);
+    }
+    assert!(factors.windows(2).all(|w| w[0].0 < w[1].0), "{n}");
+    assert_eq!(product, Scientific::from(n));
+  }
+}
@@ -0,0 +1,122 @@
+use crate::sci_common::s;
+use scientific::{ConversionError, Scientific};
+
+mod sci_common;
+
+#[test]
+fn le_limbs() {
+  for (sci, limbs) in [
+    ("0", vec![]),
+    ("1", vec![1]),
+    ("-1", vec![1]),
+    ("18446744073709551615", vec![u64::MAX]),
+    ("18446744073709551616", vec![0, 1]),
+    ("1e20", vec![0x6bc7_5e2d_6310_0000, 5]),
+  ] {
+    assert_eq!(s(sci).to_le_limbs(), Ok(limbs.clone()), "{sci}");
+    assert_eq!(
+      Scientific::from_le_limbs(sci.starts_with('-'), &limbs),
+      s(sci),
+      "{sci}"
+    );
+  }
+  assert_eq!(
+    s("1.5").to_le_limbs(),
+    Err(ConversionError::NumberIsNotAnInteger)
+  );
+  // leading zero limbs are ignored
+  assert_eq!(Scientific::from_le_limbs(true, &[5, 0, 0]), s("-5"));
+  assert_eq!(Scientific::from_le_limbs(true, &[0, 0]), Scientific::ZERO);
+}
+
+#[test]
+fn le_limbs_random() {
+  for _ in 0..1000 {
+    let value = rand::random::<u128>() >> (rand::random::<u32>() % 128);
+    let sci = Scientific::from(value);
+    let limbs = sci.to_le_limbs().unwrap();
+    let mut expected = vec![value as u64, (value >> 64) as u64];
+    while expected.last() == Some(&0) {
+      expected.pop();
+    }
+    assert_eq!(limbs, expected, "{value}");
+    assert_eq!(Scientific::from_le_limbs(false, &limbs), sci);
+  }
+}
+
+#[test]
+fn str_radix() {
+  for (sci, radix, string) in [
+    ("0", 2, "0"),
+    ("5", 2, "101"),
+    ("-255", 16, "-ff"),
+    ("35", 36, "z"),
+    ("1e3", 7, "2626"),
+    ("-12345678901234567890", 10, "-12345678901234567890"),
+  ] {
+    assert_eq!(s(sci).to_str_radix(radix).as_deref(), Ok(string), "{sci}");
+    assert_eq!(
+      Scientific::from_str_radix_integer(string, radix),
+      Ok(s(sci)),
+      "{string}"
+    );
+  }
+  assert_eq!(Scientific::from_str_radix_integer("+FF", 16), Ok(s("255")));
+  assert_eq!(
+    Scientific::from_str_radix_integer("-00", 8),
+    Ok(Scientific::ZERO)
+  );
+  for string in ["", "-", "12", "1.0", " 1", "1e3"] {
+    assert_eq!(
+      Scientific::from_str_radix_integer(string, 2),
+      Err(ConversionError::ParseError),
+      "{string}"
+    );
+  }
+  assert_eq!(
+    s("0.5").to_str_radix(2),
+    Err(ConversionError::NumberIsNotAnInteger)
+  );
+  // the trailing zeros of huge exponents are not expanded
+  assert_eq!(
+    s("1e1000000000000").to_str_radix(16),
+    Err(ConversionError::NumberTooLarge)
+  );
+  assert_eq!(
+    s("1e1000000000000").to_le_limbs(),
+    Err(ConversionError::NumberTooLarge)
+  );
+}
+
+#[test]
+fn str_radix_random() {
+  for _ in 0..1000 {
+    let value = rand::random::<i128>() >> (rand::random::<u32>() % 128);
+    let radix = rand::random::<u32>() % 35 + 2;
+    let sci = Scientific::from(value);
+    let string = sci.to_str_radix(radix).unwrap();
+    assert_eq!(i128::from_str_radix(&string, radix), Ok(value), "{string}");
+    assert_eq!(Scientific::from_str_radix_integer(&string, radix), Ok(sci));
+  }
+}
+
+#[test]
+#[should_panic = "radix must be in the range 2..=36"]
+fn str_radix_invalid() {
+  let _ = Scientific::ONE.to_str_radix(37);
+}
+
+#[test]
+fn large() {
+  // a number with 1000000 digits and a long string of zeroes
+  let digits = (0..1_000_000)
+    .map(|_| char::from(b'0' + rand::random::<u8>() % 10))
+    .collect::<String>();
+  let sci = s(&format!("9{digits}e1000"));
+
+  // the other radixes use the same conversion as the limbs
+  let limbs = sci.to_le_limbs().unwrap();
+  assert_eq!(Scientific::from_le_limbs(false, &limbs), sci);
+  let string = sci.to_str_radix(36).unwrap();
+  assert_eq!(Scientific::from_str_radix_integer(&string, 36), Ok(sci));
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "rust_decimal")]
+
+use rust_decimal::Decimal;
+use scientific::{ConversionError, Scientific};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[test]
+fn decimal() {
+  for value in [
+    "0",
+    "1.5",
+    "-0.0000000000000000000000000001",
+    "79228162514264337593543950335",
+    "-79228162514264337593543950335",
+    "7.9228162514264337593543950335",
+    "12300000000000000000000000000",
+    "-1e28",
+  ] {
+    let sci = Scientific::from_str(value).unwrap();
+    let decimal = Decimal::from_scientific(value)
+      .or_else(|_| Decimal::from_str(value))
+      .unwrap();
+    assert_eq!(Scientific::from(&decimal), sci, "{value}");
+    assert_eq!(Decimal::try_from(&sci), Ok(decimal), "{value}");
+  }
+
+  // trailing zeroes in the decimal
+  assert_eq!(
+    Scientific::from(Decimal::from_str("1.2500").unwrap()),
+    Scientific::from_str("1.25").unwrap()
+  );
+}
+
+#[test]
+fn decimal_error() {
+  for value in [
+    "1e-29",
+    "79228162514264337593543950336",
+    "1e29",
+    "1.00000000000000000000000000001",
+    "123456789012345678901234567890",
+  ] {
+    let sci = Scientific::from_str(value).unwrap();
+    assert_eq!(
+      Decimal::try_from(&sci),
+      Err(ConversionError::NumberTooLarge),
+      "{value}"
+    );
+  }
+}
+
+#[test]
+fn decimal_random() {
+  for _ in 0..10000 {
+    let decimal =
+      Decimal::from_i128_with_scale(rand::random::<i128>() >> 32, rand::random::<u32>() % 29);
+    let sci = Scientific::from(decimal);
+    assert_eq!(sci, Scientific::from_str(&decimal.to_string()).unwrap());
+    assert_eq!(Decimal::try_from(&sci), Ok(decimal.normalize()));
+  }
+}
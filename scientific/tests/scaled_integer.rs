@@ -0,0 +1,156 @@
+use scientific::{ConversionError, Rounding, Scientific};
+use std::str::FromStr;
+
+fn to_i256(value: i128) -> [u64; 4] {
+  let high = if value < 0 { u64::MAX } else { 0 };
+  [value as u64, (value >> 64) as u64, high, high]
+}
+
+#[test]
+fn decimal128() {
+  for (sci, precision, scale, result) in [
+    ("0", 10, 2, 0),
+    ("123.45", 5, 2, 12345),
+    ("-123.45", 5, 2, -12345),
+    ("123.456", 5, 2, 12346),
+    ("-123.454", 5, 2, -12345),
+    ("12", 5, 2, 1200),
+    ("12300", 3, -2, 123),
+    ("12350", 3, -2, 124),
+    ("0.004", 3, 2, 0),
+    (
+      "99999999999999999999999999999999999999",
+      38,
+      0,
+      10_i128.pow(38) - 1,
+    ),
+    (
+      "-9.9999999999999999999999999999999999999",
+      38,
+      37,
+      1 - 10_i128.pow(38),
+    ),
+  ] {
+    let sci = Scientific::from_str(sci).unwrap();
+    assert_eq!(
+      sci.to_decimal128(precision, scale, Rounding::RoundHalfAwayFromZero),
+      Ok(result),
+      "{sci} to decimal128"
+    );
+    assert_eq!(
+      sci.to_decimal256(precision, scale, Rounding::RoundHalfAwayFromZero),
+      Ok(to_i256(result)),
+      "{sci} to decimal256"
+    );
+    let rounded = sci.round(
+      scientific::Decimals(isize::from(scale)),
+      Rounding::RoundHalfAwayFromZero,
+    );
+    assert_eq!(Scientific::from_scaled_i128(result, scale), rounded);
+    assert_eq!(
+      Scientific::from_scaled_i256(to_i256(result), scale),
+      rounded
+    );
+  }
+}
+
+#[test]
+fn decimal128_too_large() {
+  for (sci, precision, scale) in [
+    ("123.45", 4, 2),
+    ("99.995", 4, 2),
+    ("1e38", 38, 0),
+    ("1", 38, 38),
+  ] {
+    let sci = Scientific::from_str(sci).unwrap();
+    assert_eq!(
+      sci.to_decimal128(precision, scale, Rounding::RoundHalfAwayFromZero),
+      Err(ConversionError::NumberTooLarge),
+      "{sci} to decimal128"
+    );
+  }
+  assert_eq!(
+    Scientific::from_str("1e38")
+      .unwrap()
+      .to_decimal256(38, 0, Rounding::RoundHalfAwayFromZero),
+    Err(ConversionError::NumberTooLarge),
+  );
+
+  // the precision is not clamped to the maximum of the type
+  let one = Scientific::from_str("1").unwrap();
+  assert_eq!(
+    one.to_decimal128(39, 0, Rounding::RoundHalfAwayFromZero),
+    Err(ConversionError::PrecisionTooLarge),
+  );
+  assert_eq!(
+    one.to_decimal256(77, 0, Rounding::RoundHalfAwayFromZero),
+    Err(ConversionError::PrecisionTooLarge),
+  );
+}
+
+#[test]
+fn decimal256() {
+  let max = Scientific::from_str(&"9".repeat(76)).unwrap();
+  let value = max
+    .to_decimal256(76, 0, Rounding::RoundHalfAwayFromZero)
+    .unwrap();
+  assert_eq!(Scientific::from_scaled_i256(value, 0), max);
+  let value = (-&max)
+    .to_decimal256(76, 0, Rounding::RoundHalfAwayFromZero)
+    .unwrap();
+  assert_eq!(Scientific::from_scaled_i256(value, 0), -&max);
+  assert_eq!(
+    Scientific::from_str("1e76")
+      .unwrap()
+      .to_decimal256(76, 0, Rounding::RoundHalfAwayFromZero),
+    Err(ConversionError::NumberTooLarge),
+  );
+
+  // minimum and maximum of an i256
+  let min = Scientific::from_str(
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+  )
+  .unwrap();
+  assert_eq!(
+    Scientific::from_scaled_i256([0, 0, 0, 1 << 63], 0),
+    min.clone()
+  );
+  assert_eq!(
+    Scientific::from_scaled_i256([u64::MAX, u64::MAX, u64::MAX, u64::MAX >> 1], 2),
+    &Scientific::from_scaled_i128(1, 2) * &(&(-&min) - &Scientific::from(1))
+  );
+}
+
+#[test]
+fn decimal_random() {
+  for _ in 0..10000 {
+    let value = rand::random::<i128>() % 10_i128.pow(38);
+    let scale = rand::random::<i8>();
+    let sci = Scientific::from_scaled_i128(value, scale);
+    assert_eq!(
+      sci.to_decimal128(38, scale, Rounding::RoundHalfAwayFromZero),
+      Ok(value),
+      "{sci} to decimal128"
+    );
+    let value = [
+      rand::random::<u64>(),
+      rand::random::<u64>(),
+      rand::random::<u64>(),
+      rand::random::<u64>() >> 5,
+    ];
+    let sci = Scientific::from_scaled_i256(value, scale);
+    assert_eq!(
+      sci.to_decimal256(76, scale, Rounding::RoundHalfAwayFromZero),
+      Ok(value),
+      "{sci} to decimal256"
+    );
+    let mut neg = value;
+    let mut carry = true;
+    for limb in neg.iter_mut() {
+      let (v, c) = (!*limb).overflowing_add(u64::from(carry));
+      *limb = v;
+      carry = c;
+    }
+    assert_eq!(Scientific::from_scaled_i256(neg, scale), -&sci);
+  }
+}
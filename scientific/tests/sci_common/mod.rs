@@ -0,0 +1,7 @@
+use core::str::FromStr;
+use scientific::Scientific;
+
+// parse a value for the test tables
+pub(crate) fn s(value: &str) -> Scientific {
+  Scientific::from_str(value).unwrap()
+}
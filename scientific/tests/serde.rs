@@ -1,6 +1,7 @@
 #![cfg(feature = "serde")]
 
 use scientific::Scientific;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 #[test]
@@ -54,3 +55,197 @@ fn serde() {
     );
   }
 }
+
+#[test]
+fn as_number_without_arbitrary_precision() {
+  // the workspace tests enable `arbitrary_precision`, in which case the output is a number
+  let arbitrary_precision = serde_json::Number::from_str("0.10000000000000000001")
+    .unwrap()
+    .to_string()
+    != "0.1";
+  if arbitrary_precision {
+    return;
+  }
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Number {
+    #[serde(with = "scientific::serde::as_number")]
+    value: Scientific,
+  }
+
+  // unsupported, the private token of serde_json is written as a map
+  let number = Number {
+    value: Scientific::from_str("1.5").unwrap(),
+  };
+  assert_eq!(
+    serde_json::to_string(&number).map_err(|_| ()),
+    Ok(r#"{"value":{"$serde_json::private::Number":"1.5"}}"#.to_string())
+  );
+}
+
+#[test]
+fn adapters() {
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Adapters {
+    #[serde(with = "scientific::serde::as_string")]
+    string: Scientific,
+    #[serde(with = "scientific::serde::as_bytes")]
+    bytes: Scientific,
+    #[serde(with = "scientific::serde::as_f64")]
+    float: Scientific,
+    #[serde(with = "scientific::serde::as_fixed::Decimals::<2>")]
+    fixed: Scientific,
+    #[serde(with = "scientific::serde::as_string::option")]
+    opt_string: Option<Scientific>,
+    #[serde(with = "scientific::serde::as_bytes::option")]
+    opt_bytes: Option<Scientific>,
+    #[serde(with = "scientific::serde::as_f64::option")]
+    opt_float: Option<Scientific>,
+    #[serde(with = "scientific::serde::as_fixed::option::Decimals::<2>")]
+    opt_fixed: Option<Scientific>,
+  }
+
+  let some = Adapters {
+    string: Scientific::from_str("-12340").unwrap(),
+    bytes: Scientific::from_str("1.234").unwrap(),
+    float: Scientific::from_str("0.5").unwrap(),
+    fixed: Scientific::from_str("1.5").unwrap(),
+    opt_string: Some(Scientific::from_str("1.234").unwrap()),
+    opt_bytes: Some(Scientific::from_str("-12340").unwrap()),
+    opt_float: Some(Scientific::from_str("1.25").unwrap()),
+    opt_fixed: Some(Scientific::from_str("-2.125").unwrap()),
+  };
+  let some_json = r#"{"string":"-12340","bytes":[125,30,208],"float":0.5,"fixed":"1.50","opt_string":"1.234","opt_bytes":[129,30,208],"opt_float":1.25,"opt_fixed":"-2.13"}"#;
+  let none = Adapters {
+    string: Scientific::ZERO,
+    bytes: Scientific::ZERO,
+    float: Scientific::ZERO,
+    fixed: Scientific::ZERO,
+    opt_string: None,
+    opt_bytes: None,
+    opt_float: None,
+    opt_fixed: None,
+  };
+  let none_json = r#"{"string":"0","bytes":[],"float":0.0,"fixed":"0.00","opt_string":null,"opt_bytes":null,"opt_float":null,"opt_fixed":null}"#;
+
+  for (value, json) in [(some, some_json), (none, none_json)] {
+    assert_eq!(
+      serde_json::to_string(&value).map_err(|_| ()),
+      Ok(json.to_string()),
+    );
+    let mut from_json: Adapters = serde_json::from_str(json).unwrap();
+    // the fixed value is not exactly stored
+    from_json.fixed = value.fixed.clone();
+    from_json.opt_fixed = value.opt_fixed.clone();
+    assert_eq!(from_json, value);
+    let mut from_postcard: Adapters =
+      postcard::from_bytes(&postcard::to_extend(&value, Vec::new()).unwrap()).unwrap();
+    from_postcard.fixed = value.fixed.clone();
+    from_postcard.opt_fixed = value.opt_fixed.clone();
+    assert_eq!(from_postcard, value);
+  }
+}
+
+#[test]
+fn as_fixed() {
+  #[derive(Serialize)]
+  struct Fixed {
+    #[serde(with = "scientific::serde::as_fixed::Decimals::<3>")]
+    three: Scientific,
+    #[serde(with = "scientific::serde::as_fixed::Decimals::<0>")]
+    zero: Scientific,
+    #[serde(with = "scientific::serde::as_fixed::Decimals::<-2>")]
+    minus_two: Scientific,
+  }
+
+  for (value, three, zero, minus_two) in [
+    ("0", "0.000", "0", "0"),
+    ("1", "1.000", "1", "0"),
+    ("-0.0001", "0.000", "0", "0"),
+    ("-0.0005", "-0.001", "0", "0"),
+    ("0.01234", "0.012", "0", "0"),
+    ("12.3456", "12.346", "12", "0"),
+    ("-9.9996", "-10.000", "-10", "0"),
+    ("1234.5", "1234.500", "1235", "1200"),
+    ("1e10", "10000000000.000", "10000000000", "10000000000"),
+  ] {
+    let value = Scientific::from_str(value).unwrap();
+    assert_eq!(
+      serde_json::to_string(&Fixed {
+        three: value.clone(),
+        zero: value.clone(),
+        minus_two: value.clone(),
+      })
+      .map_err(|_| ()),
+      Ok(format!(
+        r#"{{"three":"{three}","zero":"{zero}","minus_two":"{minus_two}"}}"#
+      )),
+      "{value}"
+    );
+  }
+}
+
+#[test]
+fn numbers() {
+  use serde::de::value::{Error, StrDeserializer};
+  use serde::de::IntoDeserializer;
+
+  fn de<'de, T: IntoDeserializer<'de, Error>>(value: T) -> Result<Scientific, Error> {
+    Scientific::deserialize(value.into_deserializer())
+  }
+  fn de_exact<'de, T: IntoDeserializer<'de, Error>>(value: T) -> Result<Scientific, Error> {
+    scientific::serde::exact_float::deserialize(value.into_deserializer())
+  }
+  let sci = |s: &str| Scientific::from_str(s).unwrap();
+
+  assert_eq!(de(-5_i8), Ok(sci("-5")));
+  assert_eq!(de(i64::MIN), Ok(sci("-9223372036854775808")));
+  assert_eq!(de(u64::MAX), Ok(sci("18446744073709551615")));
+  assert_eq!(de(i128::MIN), Ok(Scientific::from(i128::MIN)));
+  assert_eq!(de(u128::MAX), Ok(Scientific::from(u128::MAX)));
+  assert_eq!(de(12.5_f64), Ok(sci("12.5")));
+  assert_eq!(de(0.1_f32), Ok(sci("0.1")));
+  assert_eq!(de_exact(0.5_f32), Ok(sci("0.5")));
+  assert_eq!(de(0.1_f64), Ok(sci("0.1")));
+  assert_eq!(
+    de_exact(0.1_f64),
+    Ok(sci(
+      "0.1000000000000000055511151231257827021181583404541015625"
+    ))
+  );
+  assert_eq!(de_exact(-12.5_f64), Ok(sci("-12.5")));
+  assert_eq!(de_exact(7_u8), Ok(sci("7")));
+  assert!(de(f64::NAN).is_err());
+  assert!(de_exact(f64::INFINITY).is_err());
+  assert_eq!(de("1.5e3"), Ok(sci("1500")));
+  assert_eq!(
+    Scientific::deserialize(StrDeserializer::<Error>::new("-0.25")),
+    Ok(sci("-0.25"))
+  );
+  assert!(de("1.5x").is_err());
+  assert!(de(true).is_err());
+
+  #[derive(Debug, PartialEq, Deserialize)]
+  struct Config {
+    amount: Scientific,
+    count: Scientific,
+    name: Scientific,
+  }
+  assert_eq!(
+    serde_json::from_str(r#"{"amount": 12.5, "count": 3, "name": "1e1000"}"#).map_err(|_| ()),
+    Ok(Config {
+      amount: sci("12.5"),
+      count: sci("3"),
+      name: sci("1e1000"),
+    })
+  );
+  assert_eq!(
+    serde_json::from_value(serde_json::json!({"amount": -0.5, "count": -7, "name": "0"}))
+      .map_err(|_| ()),
+    Ok(Config {
+      amount: sci("-0.5"),
+      count: sci("-7"),
+      name: sci("0"),
+    })
+  );
+}
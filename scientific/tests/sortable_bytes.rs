@@ -0,0 +1,106 @@
+use scientific::{ConversionError, Scientific};
+use std::str::FromStr;
+
+fn random_number() -> Scientific {
+  let r = rand::random::<u64>();
+  let len = (r % 25) as usize;
+  let mut mantissa = (0..len)
+    .map(|_| rand::random::<u8>() % 10)
+    .collect::<Vec<u8>>();
+  // create more equal digits
+  if r & 0x100 != 0 {
+    mantissa.iter_mut().skip(1).for_each(|d| *d = 9);
+  }
+  let exponent = match (r >> 10) % 4 {
+    0 => (r >> 20) as isize % 10,
+    1 => (r >> 20) as isize % 200 - 100,
+    2 => (r >> 20) as isize % 200_000 - 100_000,
+    _ => (r >> 20) as isize,
+  };
+  Scientific::from_raw_parts(r & 0x200 != 0, mantissa, exponent).unwrap()
+}
+
+#[test]
+fn sortable_bytes_order() {
+  let mut numbers = [
+    "0", "1", "-1", "2", "10", "12", "123", "1.2", "0.12", "9.99", "1e63", "1e64", "1e65", "1e-63",
+    "1e-64", "1e-65", "1e-66", "1e319", "1e320", "1e-320", "1e-321", "5e100000",
+  ]
+  .iter()
+  .map(|s| Scientific::from_str(s).unwrap())
+  .collect::<Vec<Scientific>>();
+  numbers.extend(numbers.clone().iter().map(|n| -n));
+  numbers.push(Scientific::from_raw_parts(false, vec![1], isize::MAX / 2).unwrap());
+  numbers.push(Scientific::from_raw_parts(true, vec![1], isize::MAX / 2).unwrap());
+  numbers.push(Scientific::from_raw_parts(false, vec![1], isize::MIN / 2).unwrap());
+  numbers.push(Scientific::from_raw_parts(true, vec![1], isize::MIN / 2).unwrap());
+  numbers.extend((0..1000).map(|_| random_number()));
+
+  let bytes = numbers
+    .iter()
+    .map(Scientific::to_sortable_bytes)
+    .collect::<Vec<Vec<u8>>>();
+
+  for (a, a_bytes) in numbers.iter().zip(bytes.iter()) {
+    assert_eq!(
+      Scientific::from_sortable_bytes(a_bytes).as_ref(),
+      Ok(a),
+      "round trip of {a}"
+    );
+    for (b, b_bytes) in numbers.iter().zip(bytes.iter()) {
+      assert_eq!(a.cmp(b), a_bytes.cmp(b_bytes), "compare {a} with {b}");
+    }
+  }
+}
+
+#[test]
+fn sortable_bytes_prefix_free() {
+  // the order is also preserved when concatenating the keys
+  let a = Scientific::from_str("12").unwrap();
+  let b = Scientific::from_str("123").unwrap();
+  let mut a_key = a.to_sortable_bytes();
+  a_key.extend(b.to_sortable_bytes());
+  let mut b_key = b.to_sortable_bytes();
+  b_key.extend(a.to_sortable_bytes());
+  assert!(a_key < b_key);
+}
+
+#[test]
+fn sortable_bytes_format() {
+  for (number, bytes) in [
+    ("0", vec![0x02]),
+    ("1", vec![0x03, 0x81, 0x20]),
+    ("12", vec![0x03, 0x82, 0x23, 0x00]),
+    ("-12", vec![0x01, 0x7d, 0xdc, 0xff]),
+    ("1e63", vec![0x03, 0xc1, 0x00, 0x20]),
+    ("1e-65", vec![0x03, 0x40, 0x20]),
+    ("1e-66", vec![0x03, 0x3e, 0xff, 0x20]),
+  ] {
+    let number = Scientific::from_str(number).unwrap();
+    assert_eq!(number.to_sortable_bytes(), bytes, "{number}");
+  }
+
+  for bytes in [
+    vec![],
+    vec![0x00],
+    vec![0x02, 0x00],
+    vec![0x03],
+    vec![0x03, 0x81],
+    vec![0x03, 0x81, 0x00],             // no digits
+    vec![0x03, 0x81, 0x10],             // leading zero
+    vec![0x03, 0x81, 0x21, 0x00],       // trailing zero
+    vec![0x03, 0x81, 0xb0],             // invalid digit
+    vec![0x03, 0x81, 0x2b, 0x00],       // invalid digit
+    vec![0x03, 0x81, 0x20, 0x00],       // trailing data
+    vec![0x03, 0x81, 0x23, 0x01],       // non zero after end
+    vec![0x03, 0xc0, 0x20],             // invalid exponent
+    vec![0x03, 0xc2, 0x00, 0x01, 0x20], // exponent not shortest
+    vec![0x03, 0xc2, 0x01],             // exponent incomplete
+  ] {
+    assert_eq!(
+      Scientific::from_sortable_bytes(&bytes),
+      Err(ConversionError::ParseError),
+      "{bytes:?}"
+    );
+  }
+}